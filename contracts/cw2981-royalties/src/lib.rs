@@ -60,7 +60,7 @@ pub mod entry {
     use super::*;
 
     use cosmwasm_std::entry_point;
-    use cosmwasm_std::{Binary, Deps, DepsMut, Env, MessageInfo, Response};
+    use cosmwasm_std::{Binary, Deps, DepsMut, Env, MessageInfo, Reply, Response};
     use cw721::msg::Cw721InstantiateMsg;
     use cw721::traits::{Cw721Execute, Cw721Query};
     use state::Cw2981Contract;
@@ -133,6 +133,12 @@ pub mod entry {
         let contract = Cw2981Contract::default();
         Ok(contract.migrate(deps, env, msg, CONTRACT_NAME, CONTRACT_VERSION)?)
     }
+
+    #[entry_point]
+    pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> Result<Response, ContractError> {
+        let contract = Cw2981Contract::default();
+        Ok(contract.reply(deps, &env, msg)?)
+    }
 }
 
 #[cfg(test)]
@@ -162,6 +168,13 @@ mod tests {
             minter: None,
             creator: None,
             withdraw_address: None,
+            enable_snapshots: None,
+            allow_burned_remint: None,
+            token_history_depth: None,
+            token_id_strategy: None,
+            max_supply: None,
+            base_uri: None,
+            reveal: None,
         };
         entry::instantiate(deps.as_mut(), mock_env(), info.clone(), init_msg).unwrap();
 
@@ -201,6 +214,13 @@ mod tests {
             minter: None,
             creator: None,
             withdraw_address: None,
+            enable_snapshots: None,
+            allow_burned_remint: None,
+            token_history_depth: None,
+            token_id_strategy: None,
+            max_supply: None,
+            base_uri: None,
+            reveal: None,
         };
         entry::instantiate(deps.as_mut(), mock_env(), info.clone(), init_msg).unwrap();
 
@@ -234,6 +254,13 @@ mod tests {
             minter: None,
             creator: None,
             withdraw_address: None,
+            enable_snapshots: None,
+            allow_burned_remint: None,
+            token_history_depth: None,
+            token_id_strategy: None,
+            max_supply: None,
+            base_uri: None,
+            reveal: None,
         };
         entry::instantiate(deps.as_mut(), mock_env(), info.clone(), init_msg).unwrap();
 
@@ -275,6 +302,13 @@ mod tests {
             minter: None,
             creator: None,
             withdraw_address: None,
+            enable_snapshots: None,
+            allow_burned_remint: None,
+            token_history_depth: None,
+            token_id_strategy: None,
+            max_supply: None,
+            base_uri: None,
+            reveal: None,
         };
         let env = mock_env();
         entry::instantiate(deps.as_mut(), env.clone(), info.clone(), init_msg).unwrap();
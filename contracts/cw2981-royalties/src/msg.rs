@@ -8,6 +8,7 @@ use cw721::msg::{
     AllNftInfoResponse, ApprovalResponse, ApprovalsResponse, MinterResponse, NftInfoResponse,
     NumTokensResponse, OperatorResponse, OperatorsResponse, OwnerOfResponse, TokensResponse,
 };
+pub use cw721::msg::{CheckRoyaltiesResponse, RoyaltiesInfoResponse};
 use cw721::{
     error::Cw721ContractError,
     execute::{assert_creator, assert_minter},
@@ -224,21 +225,6 @@ impl From<QueryMsg> for Cw721QueryMsg<DefaultOptionMetadataExtensionWithRoyalty,
     }
 }
 
-#[cw_serde]
-pub struct RoyaltiesInfoResponse {
-    pub address: String,
-    // Note that this must be the same denom as that passed in to RoyaltyInfo
-    // rounding up or down is at the discretion of the implementer
-    pub royalty_amount: Uint128,
-}
-
-/// Shows if the contract implements royalties
-/// if royalty_payments is true, marketplaces should pay them
-#[cw_serde]
-pub struct CheckRoyaltiesResponse {
-    pub royalty_payments: bool,
-}
-
 pub type MetadataWithRoyaltyMsg = MetadataWithRoyalty;
 
 // this is simply a copy of NftExtension
@@ -32,7 +32,7 @@ pub mod entry {
 
     #[cfg(not(feature = "library"))]
     use cosmwasm_std::entry_point;
-    use cosmwasm_std::{Binary, Deps, DepsMut, Env, MessageInfo, Response};
+    use cosmwasm_std::{Binary, Deps, DepsMut, Env, MessageInfo, Reply, Response};
     use cw721::traits::{Cw721Execute, Cw721Query};
     use error::ContractError;
     use msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
@@ -70,6 +70,12 @@ pub mod entry {
         let contract = Cw721BaseContract::default();
         contract.migrate(deps, env, msg, CONTRACT_NAME, CONTRACT_VERSION)
     }
+
+    #[cfg_attr(not(feature = "library"), entry_point)]
+    pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> Result<Response, ContractError> {
+        let contract = Cw721BaseContract::default();
+        contract.reply(deps, &env, msg)
+    }
 }
 
 #[cfg(test)]
@@ -100,6 +106,13 @@ mod tests {
             minter: None,
             creator: None,
             withdraw_address: None,
+            enable_snapshots: None,
+            allow_burned_remint: None,
+            token_history_depth: None,
+            token_id_strategy: None,
+            max_supply: None,
+            base_uri: None,
+            reveal: None,
         };
         contract
             .instantiate(deps.as_mut(), &mock_env(), &info.clone(), init_msg)
@@ -9,15 +9,17 @@ use crate::{
     error::ContractError, msg::InstantiateMsg, msg::QueryMsg, DefaultOptionalNftExtension,
 };
 use cw721::error::Cw721ContractError;
+use cw721::execute::LEGACY_NFT_INFO_MIGRATION_LIMIT;
 use cw721::msg::CollectionInfoAndExtensionResponse;
 use cw721::msg::{
-    ApprovalResponse, Cw721ExecuteMsg, NftInfoResponse, OperatorResponse, OperatorsResponse,
-    OwnerOfResponse, TokensResponse,
+    ApprovalResponse, BatchMintResponse, Cw721ExecuteMsg, Cw721MigrateMsg, MintMsg, MintResponse,
+    NftInfoResponse, OperatorResponse, OperatorsResponse, OwnerOfResponse, TokensResponse,
 };
 use cw721::receiver::Cw721ReceiveMsg;
-use cw721::state::{CREATOR, MINTER};
+use cw721::state::{LegacyNftInfo, NftProvenance, TokenIdStrategy, CREATOR, MINTER};
 use cw721::{traits::Cw721Query, Approval, Expiration};
 use cw_ownable::{Action, Ownership, OwnershipError};
+use cw_storage_plus::Map;
 pub struct MockAddrFactory<'a> {
     api: MockApi,
     addrs: std::collections::BTreeMap<&'a str, Addr>,
@@ -109,6 +111,8 @@ fn proper_instantiation() {
             symbol: SYMBOL.to_string(),
             extension: None,
             updated_at: env.block.time,
+            max_supply: None,
+            supply_sealed: false,
         }
     );
 
@@ -174,6 +178,8 @@ fn proper_instantiation_with_collection_info() {
             symbol: SYMBOL.to_string(),
             extension: None,
             updated_at: env.block.time,
+            max_supply: None,
+            supply_sealed: false,
         }
     );
 
@@ -299,6 +305,59 @@ fn test_mint() {
         .unwrap();
     assert_eq!(1, tokens.tokens.len());
     assert_eq!(vec![token_id], tokens.tokens);
+
+    // mint timestamps are saved for generated token ids
+    contract
+        .base_contract
+        .config
+        .token_id_strategy
+        .save(
+            deps.as_mut().storage,
+            &TokenIdStrategy::Sequential {
+                offset: 1,
+                padding: None,
+            },
+        )
+        .unwrap();
+    let mint_msg = Cw721ExecuteMsg::Mint {
+        token_id: "".to_string(),
+        owner: owner.to_string(),
+        token_uri: None,
+        extension: None,
+    };
+    let res = contract
+        .execute(deps.as_mut(), mock_env(), addrs.info("minter"), mint_msg)
+        .unwrap();
+    let MintResponse { token_id } = from_json(res.data.unwrap()).unwrap();
+    let batch_mint_msg = Cw721ExecuteMsg::BatchMint {
+        tokens: vec![
+            MintMsg {
+                token_id: "".to_string(),
+                owner: owner.to_string(),
+                token_uri: None,
+                extension: None,
+            };
+            2
+        ],
+    };
+    let res = contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            addrs.info("minter"),
+            batch_mint_msg,
+        )
+        .unwrap();
+    let BatchMintResponse { token_ids } = from_json(res.data.unwrap()).unwrap();
+    assert_eq!(token_ids.len(), 2);
+    for token_id in token_ids.iter().chain([&token_id]) {
+        let mint_timestamp = contract
+            .mint_timestamps
+            .load(deps.as_ref().storage, token_id)
+            .unwrap();
+        assert_eq!(mint_timestamp, mock_env().block.time);
+    }
+    assert!(!contract.mint_timestamps.has(deps.as_ref().storage, ""));
 }
 
 #[test]
@@ -474,8 +533,29 @@ fn test_burn() {
         .unwrap();
     assert!(tokens.tokens.is_empty());
 
+    // burned token id can't be minted again
+    let error = contract
+        .execute(deps.as_mut(), env.clone(), minter_info.clone(), mint_msg)
+        .unwrap_err();
+    assert_eq!(
+        error,
+        ContractError::Cw721(Cw721ContractError::TokenBurned {
+            token_id: token_id.clone()
+        })
+    );
+
     // assert invalid nft throws error
-    // - mint again
+    // - mint another nft
+    let token_id = "petrify2".to_string();
+    let mint_msg = Cw721ExecuteMsg::Mint {
+        token_id: token_id.clone(),
+        owner: minter.to_string(),
+        token_uri: None,
+        extension: None,
+    };
+    let burn_msg = Cw721ExecuteMsg::Burn {
+        token_id: token_id.clone(),
+    };
     contract
         .execute(deps.as_mut(), env.clone(), minter_info.clone(), mint_msg)
         .unwrap();
@@ -1672,3 +1752,168 @@ fn test_all_tokens() {
         }
     );
 }
+
+#[test]
+fn test_migrate_provenance() {
+    let mut deps = mock_dependencies();
+    let mut addrs = MockAddrFactory::new(deps.api);
+    let creator = addrs.addr("creator");
+    let minter = addrs.addr("minter");
+    let contract = setup_contract(deps.as_mut(), 1, &creator, &minter);
+
+    let env = mock_env();
+    let mint_msg = Cw721ExecuteMsg::Mint {
+        token_id: "petrify".to_string(),
+        owner: minter.to_string(),
+        token_uri: None,
+        extension: None,
+    };
+    contract
+        .execute(deps.as_mut(), env.clone(), addrs.info("minter"), mint_msg)
+        .unwrap();
+    // NFT minted before provenance was introduced
+    contract.provenance_migrated.remove(deps.as_mut().storage);
+    let nft_owner_info = &contract.base_contract.config.nft_owner_info;
+    let mut token = nft_owner_info
+        .load(deps.as_ref().storage, "petrify")
+        .unwrap();
+    token.provenance = NftProvenance::default();
+    nft_owner_info
+        .save(deps.as_mut().storage, "petrify", &token)
+        .unwrap();
+
+    let res = contract
+        .migrate(
+            deps.as_mut(),
+            env.clone(),
+            Cw721MigrateMsg::WithUpdate {
+                minter: None,
+                creator: None,
+            },
+            "contract_name",
+            "contract_version",
+        )
+        .unwrap();
+    assert!(res
+        .attributes
+        .iter()
+        .any(|attr| attr.key == "migrated_provenance" && attr.value == "1"));
+    let token = nft_owner_info
+        .load(deps.as_ref().storage, "petrify")
+        .unwrap();
+    assert_eq!(token.provenance.minted_at, Some(env.block.time));
+
+    // provenance is backfilled only once
+    let mut token = nft_owner_info
+        .load(deps.as_ref().storage, "petrify")
+        .unwrap();
+    token.provenance = NftProvenance::default();
+    nft_owner_info
+        .save(deps.as_mut().storage, "petrify", &token)
+        .unwrap();
+    let res = contract
+        .migrate(
+            deps.as_mut(),
+            env.clone(),
+            Cw721MigrateMsg::WithUpdate {
+                minter: None,
+                creator: None,
+            },
+            "contract_name",
+            "contract_version",
+        )
+        .unwrap();
+    assert!(!res
+        .attributes
+        .iter()
+        .any(|attr| attr.key == "migrated_provenance"));
+
+    // large legacy collections are backfilled once all NFTs are migrated
+    let mut deps = mock_dependencies();
+    let contract = setup_contract(deps.as_mut(), 1, &creator, &minter);
+    contract.provenance_migrated.remove(deps.as_mut().storage);
+    let legacy_nft_info: Map<&str, LegacyNftInfo<DefaultOptionalNftExtension>> = Map::new("tokens");
+    for i in 0..LEGACY_NFT_INFO_MIGRATION_LIMIT + 1 {
+        let token_id = format!("{i:04}");
+        legacy_nft_info
+            .save(
+                deps.as_mut().storage,
+                &token_id,
+                &LegacyNftInfo {
+                    owner: minter.clone(),
+                    approvals: None,
+                    token_uri: Some(format!("ipfs://legacy/{i}")),
+                    extension: None,
+                },
+            )
+            .unwrap();
+        contract
+            .mint_timestamps
+            .save(deps.as_mut().storage, &token_id, &env.block.time)
+            .unwrap();
+    }
+    let migrate_msg = Cw721MigrateMsg::WithUpdate {
+        minter: None,
+        creator: None,
+    };
+    let res = contract
+        .migrate(
+            deps.as_mut(),
+            env.clone(),
+            migrate_msg.clone(),
+            "contract_name",
+            "contract_version",
+        )
+        .unwrap();
+    assert!(res
+        .attributes
+        .iter()
+        .any(|attr| attr.key == "legacy_nft_info_cursor"));
+    assert!(!res
+        .attributes
+        .iter()
+        .any(|attr| attr.key == "migrated_provenance"));
+    assert_eq!(
+        contract
+            .provenance_migrated
+            .may_load(deps.as_ref().storage)
+            .unwrap(),
+        None
+    );
+    // pending legacy NFT keeps its data
+    let pending_token_id = format!("{:04}", LEGACY_NFT_INFO_MIGRATION_LIMIT);
+    let legacy = legacy_nft_info
+        .load(deps.as_ref().storage, &pending_token_id)
+        .unwrap();
+    assert_eq!(
+        legacy.token_uri,
+        Some(format!("ipfs://legacy/{LEGACY_NFT_INFO_MIGRATION_LIMIT}"))
+    );
+
+    let res = contract
+        .migrate(
+            deps.as_mut(),
+            env.clone(),
+            migrate_msg,
+            "contract_name",
+            "contract_version",
+        )
+        .unwrap();
+    assert!(res
+        .attributes
+        .iter()
+        .any(|attr| attr.key == "migrated_provenance"
+            && attr.value == (LEGACY_NFT_INFO_MIGRATION_LIMIT + 1).to_string()));
+    let nft_info = contract
+        .base_contract
+        .query_nft_info(deps.as_ref().storage, pending_token_id.clone())
+        .unwrap();
+    assert_eq!(
+        nft_info.token_uri,
+        Some(format!("ipfs://legacy/{LEGACY_NFT_INFO_MIGRATION_LIMIT}"))
+    );
+    let token = nft_owner_info
+        .load(deps.as_ref().storage, &pending_token_id)
+        .unwrap();
+    assert_eq!(token.provenance.minted_at, Some(env.block.time));
+}
@@ -2,13 +2,18 @@ use crate::{
     error::ContractError, msg::InstantiateMsg, state::DefaultCw721ExpirationContract,
     CONTRACT_NAME, CONTRACT_VERSION,
 };
-use cosmwasm_std::{Binary, DepsMut, Empty, Env, MessageInfo, Response};
+use cosmwasm_std::{Binary, DepsMut, Empty, Env, MessageInfo, Order, Response, StdResult};
 use cw721::{
-    msg::{Cw721ExecuteMsg, Cw721InstantiateMsg, Cw721MigrateMsg},
+    error::Cw721ContractError,
+    execute::assert_no_legacy_migration,
+    msg::{Cw721ExecuteMsg, Cw721InstantiateMsg, Cw721MigrateMsg, Permit},
     traits::Cw721Execute,
     Expiration,
 };
-use cw721::{DefaultOptionalCollectionExtensionMsg, DefaultOptionalNftExtensionMsg};
+use cw721::{
+    DefaultOptionalCollectionExtension, DefaultOptionalCollectionExtensionMsg,
+    DefaultOptionalNftExtension, DefaultOptionalNftExtensionMsg,
+};
 
 impl DefaultCw721ExpirationContract<'static> {
     // -- instantiate --
@@ -26,6 +31,8 @@ impl DefaultCw721ExpirationContract<'static> {
         contract
             .expiration_days
             .save(deps.storage, &msg.expiration_days)?;
+        // NFTs minted from now on have provenance, nothing to backfill
+        contract.provenance_migrated.save(deps.storage, &true)?;
         Ok(contract.base_contract.instantiate_with_version(
             deps,
             &env,
@@ -37,6 +44,13 @@ impl DefaultCw721ExpirationContract<'static> {
                 minter: msg.minter,
                 creator: msg.creator,
                 withdraw_address: msg.withdraw_address,
+                enable_snapshots: None,
+                allow_burned_remint: None,
+                token_history_depth: None,
+                token_id_strategy: None,
+                max_supply: None,
+                base_uri: None,
+                reveal: None,
             },
             CONTRACT_NAME,
             CONTRACT_VERSION,
@@ -55,16 +69,9 @@ impl DefaultCw721ExpirationContract<'static> {
             Empty,
         >,
     ) -> Result<Response<Empty>, ContractError> {
+        assert_no_legacy_migration(deps.storage)?;
         let contract = DefaultCw721ExpirationContract::default();
         match msg {
-            Cw721ExecuteMsg::Mint {
-                token_id,
-                owner,
-                token_uri,
-                extension,
-            } => {
-                contract.mint_with_timestamp(deps, env, info, token_id, owner, token_uri, extension)
-            }
             Cw721ExecuteMsg::Approve {
                 spender,
                 token_id,
@@ -85,61 +92,168 @@ impl DefaultCw721ExpirationContract<'static> {
             Cw721ExecuteMsg::Burn { token_id } => {
                 contract.burn_nft_include_nft_expired(deps, env, info, token_id)
             }
+            Cw721ExecuteMsg::BatchTransferNft {
+                recipient,
+                token_ids,
+            } => contract
+                .batch_transfer_nft_include_nft_expired(deps, env, info, recipient, token_ids),
+            Cw721ExecuteMsg::BatchSendNft {
+                contract: recipient,
+                token_ids,
+                msg,
+            } => contract
+                .batch_send_nft_include_nft_expired(deps, env, info, recipient, token_ids, msg),
+            Cw721ExecuteMsg::BatchBurn { token_ids } => {
+                contract.batch_burn_nft_include_nft_expired(deps, env, info, token_ids)
+            }
+            Cw721ExecuteMsg::SetUser {
+                token_id,
+                user,
+                expires,
+            } => contract.set_user_include_nft_expired(deps, env, info, token_id, user, expires),
+            Cw721ExecuteMsg::Lock { token_id } => {
+                contract.lock_include_nft_expired(deps, env, info, token_id)
+            }
+            Cw721ExecuteMsg::OfferTransfer {
+                token_id,
+                recipient,
+                expires,
+            } => contract
+                .offer_transfer_include_nft_expired(deps, env, info, token_id, recipient, expires),
+            Cw721ExecuteMsg::AcceptTransfer { token_id } => {
+                contract.accept_transfer_include_nft_expired(deps, env, info, token_id)
+            }
+            Cw721ExecuteMsg::PermitApprove {
+                permit,
+                pubkey,
+                signature,
+            } => contract
+                .permit_approve_include_nft_expired(deps, env, info, permit, pubkey, signature),
+            Cw721ExecuteMsg::PermitTransfer {
+                permit,
+                pubkey,
+                signature,
+            } => contract
+                .permit_transfer_include_nft_expired(deps, env, info, permit, pubkey, signature),
+            // mint timestamps are saved by the `after_mint` hook
             _ => {
-                let response = contract.base_contract.execute(deps, &env, &info, msg)?;
+                let response = Cw721Execute::execute(&contract, deps, &env, &info, msg)?;
                 Ok(response)
             }
         }
     }
 
-    #[allow(clippy::too_many_arguments)]
-    pub fn mint_with_timestamp(
+    pub fn approve_include_nft_expired(
         &self,
         deps: DepsMut,
         env: Env,
         info: MessageInfo,
+        spender: String,
         token_id: String,
-        owner: String,
-        token_uri: Option<String>,
-        extension: DefaultOptionalNftExtensionMsg,
+        expires: Option<Expiration>,
     ) -> Result<Response<Empty>, ContractError> {
-        let mint_timstamp = env.block.time;
-        self.mint_timestamps
-            .save(deps.storage, &token_id, &mint_timstamp)?;
-        let res = self
+        self.assert_nft_expired(deps.as_ref(), &env, token_id.as_str())?;
+        Ok(self
             .base_contract
-            .mint(deps, &env, &info, token_id, owner, token_uri, extension)?
-            .add_attribute("mint_timestamp", mint_timstamp.to_string());
-        Ok(res)
+            .approve(deps, &env, &info, spender, token_id, expires)?)
     }
 
-    pub fn approve_include_nft_expired(
+    pub fn revoke_include_nft_expired(
         &self,
         deps: DepsMut,
         env: Env,
         info: MessageInfo,
         spender: String,
         token_id: String,
+    ) -> Result<Response<Empty>, ContractError> {
+        self.assert_nft_expired(deps.as_ref(), &env, token_id.as_str())?;
+        Ok(self
+            .base_contract
+            .revoke(deps, &env, &info, spender, token_id)?)
+    }
+
+    pub fn set_user_include_nft_expired(
+        &self,
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        token_id: String,
+        user: Option<String>,
         expires: Option<Expiration>,
     ) -> Result<Response<Empty>, ContractError> {
         self.assert_nft_expired(deps.as_ref(), &env, token_id.as_str())?;
         Ok(self
             .base_contract
-            .approve(deps, &env, &info, spender, token_id, expires)?)
+            .set_user(deps, &env, &info, token_id, user, expires)?)
     }
 
-    pub fn revoke_include_nft_expired(
+    pub fn lock_include_nft_expired(
         &self,
         deps: DepsMut,
         env: Env,
         info: MessageInfo,
-        spender: String,
         token_id: String,
+    ) -> Result<Response<Empty>, ContractError> {
+        self.assert_nft_expired(deps.as_ref(), &env, token_id.as_str())?;
+        Ok(self.base_contract.lock(deps, &env, &info, token_id)?)
+    }
+
+    pub fn offer_transfer_include_nft_expired(
+        &self,
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        token_id: String,
+        recipient: String,
+        expires: Option<Expiration>,
     ) -> Result<Response<Empty>, ContractError> {
         self.assert_nft_expired(deps.as_ref(), &env, token_id.as_str())?;
         Ok(self
             .base_contract
-            .revoke(deps, &env, &info, spender, token_id)?)
+            .offer_transfer(deps, &env, &info, token_id, recipient, expires)?)
+    }
+
+    pub fn accept_transfer_include_nft_expired(
+        &self,
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        token_id: String,
+    ) -> Result<Response<Empty>, ContractError> {
+        self.assert_nft_expired(deps.as_ref(), &env, token_id.as_str())?;
+        Ok(self
+            .base_contract
+            .accept_transfer(deps, &env, &info, token_id)?)
+    }
+
+    pub fn permit_approve_include_nft_expired(
+        &self,
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        permit: Permit,
+        pubkey: Binary,
+        signature: Binary,
+    ) -> Result<Response<Empty>, ContractError> {
+        self.assert_nft_expired(deps.as_ref(), &env, permit.token_id.as_str())?;
+        Ok(self
+            .base_contract
+            .permit_approve(deps, &env, &info, permit, pubkey, signature)?)
+    }
+
+    pub fn permit_transfer_include_nft_expired(
+        &self,
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        permit: Permit,
+        pubkey: Binary,
+        signature: Binary,
+    ) -> Result<Response<Empty>, ContractError> {
+        self.assert_nft_expired(deps.as_ref(), &env, permit.token_id.as_str())?;
+        Ok(self
+            .base_contract
+            .permit_transfer(deps, &env, &info, permit, pubkey, signature)?)
     }
 
     pub fn transfer_nft_include_nft_expired(
@@ -182,17 +296,141 @@ impl DefaultCw721ExpirationContract<'static> {
         Ok(self.base_contract.burn_nft(deps, &env, &info, token_id)?)
     }
 
+    pub fn batch_transfer_nft_include_nft_expired(
+        &self,
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        recipient: String,
+        token_ids: Vec<String>,
+    ) -> Result<Response<Empty>, ContractError> {
+        for token_id in &token_ids {
+            self.assert_nft_expired(deps.as_ref(), &env, token_id)?;
+        }
+        Ok(self
+            .base_contract
+            .batch_transfer_nft(deps, &env, &info, recipient, token_ids)?)
+    }
+
+    pub fn batch_send_nft_include_nft_expired(
+        &self,
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        contract: String,
+        token_ids: Vec<String>,
+        msg: Binary,
+    ) -> Result<Response<Empty>, ContractError> {
+        for token_id in &token_ids {
+            self.assert_nft_expired(deps.as_ref(), &env, token_id)?;
+        }
+        Ok(self
+            .base_contract
+            .batch_send_nft(deps, &env, &info, contract, token_ids, msg)?)
+    }
+
+    pub fn batch_burn_nft_include_nft_expired(
+        &self,
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        token_ids: Vec<String>,
+    ) -> Result<Response<Empty>, ContractError> {
+        for token_id in &token_ids {
+            self.assert_nft_expired(deps.as_ref(), &env, token_id)?;
+        }
+        Ok(self
+            .base_contract
+            .batch_burn_nft(deps, &env, &info, token_ids)?)
+    }
+
     // -- migrate --
     pub fn migrate(
         &self,
-        deps: DepsMut,
+        mut deps: DepsMut,
         env: Env,
         msg: Cw721MigrateMsg,
         contract_name: &str,
         contract_version: &str,
     ) -> Result<Response<Empty>, ContractError> {
-        Ok(self
+        let response =
+            self.base_contract
+                .migrate(deps.branch(), env, msg, contract_name, contract_version)?;
+        self.migrate_provenance(deps, response)
+    }
+
+    /// Backfills `minted_at` provenance of NFTs minted before provenance was introduced,
+    /// using the mint timestamps tracked by this contract. Backfilled only once, after legacy
+    /// NFT info is fully migrated.
+    fn migrate_provenance(
+        &self,
+        deps: DepsMut,
+        response: Response<Empty>,
+    ) -> Result<Response<Empty>, ContractError> {
+        if self
+            .provenance_migrated
+            .may_load(deps.storage)?
+            .unwrap_or(false)
+        {
+            return Ok(response);
+        }
+        // legacy NFTs are not fully migrated yet, backfilled by a later migration
+        if self
             .base_contract
-            .migrate(deps, env, msg, contract_name, contract_version)?)
+            .config
+            .legacy_nft_info_cursor
+            .exists(deps.storage)
+        {
+            return Ok(response);
+        }
+        let mint_timestamps = self
+            .mint_timestamps
+            .range(deps.storage, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<_>>>()?;
+        let nft_owner_info = &self.base_contract.config.nft_owner_info;
+        let mut migrated = 0;
+        for (token_id, mint_timestamp) in mint_timestamps {
+            // skip burned NFTs
+            let Some(mut token) = nft_owner_info.may_load(deps.storage, &token_id)? else {
+                continue;
+            };
+            if token.provenance.minted_at.is_none() {
+                token.provenance.minted_at = Some(mint_timestamp);
+                nft_owner_info.save(deps.storage, &token_id, &token)?;
+                migrated += 1;
+            }
+        }
+        self.provenance_migrated.save(deps.storage, &true)?;
+        if migrated == 0 {
+            return Ok(response);
+        }
+        Ok(response.add_attribute("migrated_provenance", migrated.to_string()))
+    }
+}
+
+impl
+    Cw721Execute<
+        DefaultOptionalNftExtension,
+        DefaultOptionalNftExtensionMsg,
+        DefaultOptionalCollectionExtension,
+        DefaultOptionalCollectionExtensionMsg,
+        Empty,
+        Empty,
+    > for DefaultCw721ExpirationContract<'static>
+{
+    /// Saves mint timestamp of each minted NFT, including batch mints, vouchers and claims.
+    fn after_mint(
+        &self,
+        deps: DepsMut,
+        env: &Env,
+        _info: &MessageInfo,
+        _owner: &str,
+        token_id: &str,
+        response: Response,
+    ) -> Result<Response, Cw721ContractError> {
+        let mint_timstamp = env.block.time;
+        self.mint_timestamps
+            .save(deps.storage, token_id, &mint_timstamp)?;
+        Ok(response.add_attribute("mint_timestamp", mint_timstamp.to_string()))
     }
 }
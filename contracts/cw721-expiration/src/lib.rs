@@ -30,9 +30,10 @@ pub mod entry {
 
     #[cfg(not(feature = "library"))]
     use cosmwasm_std::entry_point;
-    use cosmwasm_std::{Binary, Deps, DepsMut, Env, MessageInfo, Response};
+    use cosmwasm_std::{Binary, Deps, DepsMut, Env, MessageInfo, Reply, Response};
     use cw721::{
-        msg::Cw721ExecuteMsg, DefaultOptionalCollectionExtensionMsg, DefaultOptionalNftExtensionMsg,
+        msg::Cw721ExecuteMsg, traits::Cw721Execute, DefaultOptionalCollectionExtensionMsg,
+        DefaultOptionalNftExtensionMsg,
     };
 
     // This makes a conscious choice on the various generics used by the contract
@@ -77,6 +78,12 @@ pub mod entry {
         let contract = DefaultCw721ExpirationContract::default();
         contract.migrate(deps, env, msg, CONTRACT_NAME, CONTRACT_VERSION)
     }
+
+    #[cfg_attr(not(feature = "library"), entry_point)]
+    pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> Result<Response, ContractError> {
+        let contract = DefaultCw721ExpirationContract::default();
+        Ok(contract.base_contract.reply(deps, &env, msg)?)
+    }
 }
 
 #[cfg(test)]
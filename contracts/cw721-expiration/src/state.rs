@@ -14,6 +14,8 @@ use cw_storage_plus::{Item, Map};
 pub struct DefaultCw721ExpirationContract<'a> {
     pub expiration_days: Item<u16>, // max 65535 days
     pub mint_timestamps: Map<&'a str, Timestamp>,
+    /// Set once `minted_at` provenance is in place, so it is not backfilled on every migration.
+    pub provenance_migrated: Item<bool>,
     pub base_contract: Cw721OnchainExtensions<'a>,
 }
 
@@ -22,6 +24,7 @@ impl Default for DefaultCw721ExpirationContract<'static> {
         Self {
             expiration_days: Item::new("expiration_days"),
             mint_timestamps: Map::new("mint_timestamps"),
+            provenance_migrated: Item::new("provenance_migrated"),
             base_contract: Cw721OnchainExtensions::default(),
         }
     }
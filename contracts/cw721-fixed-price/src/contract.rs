@@ -66,6 +66,13 @@ pub fn instantiate(
                 minter: None,
                 creator: None,
                 withdraw_address: msg.withdraw_address,
+                enable_snapshots: None,
+                allow_burned_remint: None,
+                token_history_depth: None,
+                token_id_strategy: None,
+                max_supply: None,
+                base_uri: None,
+                reveal: None,
             })?,
             funds: vec![],
             admin: None,
@@ -268,6 +275,13 @@ mod tests {
                         minter: None,
                         creator: None,
                         withdraw_address: None,
+                        enable_snapshots: None,
+                        allow_burned_remint: None,
+                        token_history_depth: None,
+                        token_id_strategy: None,
+                        max_supply: None,
+                        base_uri: None,
+                        reveal: None,
                     })
                     .unwrap(),
                     funds: vec![],
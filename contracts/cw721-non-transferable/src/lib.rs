@@ -19,7 +19,7 @@ pub mod entry {
     use crate::query::admin;
     use crate::state::{Config, CONFIG};
     use cosmwasm_std::{
-        entry_point, to_json_binary, Addr, Binary, Deps, DepsMut, Env, MessageInfo, Response,
+        entry_point, to_json_binary, Addr, Binary, Deps, DepsMut, Env, MessageInfo, Reply, Response,
     };
     use cw721::error::Cw721ContractError;
     use cw721::msg::{Cw721ExecuteMsg, Cw721InstantiateMsg};
@@ -50,6 +50,13 @@ pub mod entry {
             minter: msg.minter,
             creator: msg.creator,
             withdraw_address: msg.withdraw_address,
+            enable_snapshots: None,
+            allow_burned_remint: None,
+            token_history_depth: None,
+            token_id_strategy: None,
+            max_supply: None,
+            base_uri: None,
+            reveal: None,
         };
 
         Cw721NonTransferableContract::default().instantiate_with_version(
@@ -98,6 +105,9 @@ pub mod entry {
                     extension,
                 } => Cw721NonTransferableContract::default()
                     .mint(deps, &env, &info, token_id, owner, token_uri, extension),
+                Cw721ExecuteMsg::BatchMint { tokens } => {
+                    Cw721NonTransferableContract::default().batch_mint(deps, &env, &info, tokens)
+                }
                 _ => Err(Cw721ContractError::Ownership(
                     cw721::OwnershipError::NotOwner,
                 )),
@@ -122,4 +132,10 @@ pub mod entry {
         let contract = Cw721EmptyExtensions::default();
         contract.migrate(deps, env, msg, CONTRACT_NAME, CONTRACT_VERSION)
     }
+
+    #[entry_point]
+    pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> Result<Response, Cw721ContractError> {
+        let contract = Cw721EmptyExtensions::default();
+        contract.reply(deps, &env, msg)
+    }
 }
@@ -1,10 +1,13 @@
 use std::{num::ParseIntError, str::ParseBoolError};
 
-use cosmwasm_std::StdError;
+use cosmwasm_std::{StdError, Timestamp};
 use cw_ownable::OwnershipError;
+use cw_utils::PaymentError;
 use thiserror::Error;
 use url::ParseError;
 
+use crate::state::PauseScope;
+
 #[derive(Error, Debug, PartialEq)]
 pub enum Cw721ContractError {
     #[error(transparent)]
@@ -31,6 +34,9 @@ pub enum Cw721ContractError {
     #[error(transparent)]
     Version(#[from] cw2::VersionError),
 
+    #[error(transparent)]
+    Payment(#[from] PaymentError),
+
     #[error("token_id already claimed")]
     Claimed {},
 
@@ -43,9 +49,126 @@ pub enum Cw721ContractError {
     #[error("Caller is neither minter nor collection creator")]
     NotMinterOrCreator {},
 
+    #[error("Caller is neither pauser nor collection creator")]
+    NotPauserOrCreator {},
+
+    #[error("Collection {scope} paused")]
+    Paused { scope: PauseScope },
+
     #[error("Cannot set approval that is already expired")]
     Expired {},
 
+    #[error("Trading not started yet, starts at {start_trading_time}")]
+    TradingNotStarted { start_trading_time: Timestamp },
+
+    #[error("NFT info of {token_id} is frozen")]
+    NftInfoFrozen { token_id: String },
+
+    #[error("Collection info is frozen")]
+    CollectionInfoFrozen {},
+
+    #[error("Token {token_id} has been burned and can't be minted again")]
+    TokenBurned { token_id: String },
+
+    #[error("Supply is sealed, no more NFTs can be minted")]
+    SupplySealed {},
+
+    #[error("Max supply of {max_supply} exceeded")]
+    MaxSupplyExceeded { max_supply: u64 },
+
+    #[error("Base uri is frozen")]
+    BaseUriFrozen {},
+
+    #[error("Reveal commitment must be a sha256 hash")]
+    InvalidRevealCommitment {},
+
+    #[error("Reveal must be committed before minting")]
+    RevealCommitAfterMint {},
+
+    #[error("No reveal committed")]
+    RevealNotCommitted {},
+
+    #[error("Already revealed")]
+    AlreadyRevealed {},
+
+    #[error("Base uri and salt do not match reveal commitment")]
+    InvalidRevealPreimage {},
+
+    #[error("Operator {operator} is not allowed")]
+    OperatorNotAllowed { operator: String },
+
+    #[error("Token {token_id} is locked")]
+    TokenLocked { token_id: String },
+
+    #[error("Token {token_id} is not locked")]
+    TokenNotLocked { token_id: String },
+
+    #[error("No transfer offer for token {token_id}")]
+    NoTransferOffer { token_id: String },
+
+    #[error("Transfer offer for token {token_id} has expired")]
+    TransferOfferExpired { token_id: String },
+
+    #[error("Only the recipient can accept a transfer offer")]
+    NotTransferOfferRecipient {},
+
+    #[error("Permit has expired")]
+    PermitExpired {},
+
+    #[error("Permit is not valid for this chain or contract")]
+    InvalidPermitDomain {},
+
+    #[error("Invalid permit nonce, expected {expected}")]
+    InvalidPermitNonce { expected: u64 },
+
+    #[error("Public key does not match the NFT owner")]
+    PermitPubkeyMismatch {},
+
+    #[error("Invalid permit signature")]
+    InvalidPermitSignature {},
+
+    #[error("Invalid secp256k1 public key")]
+    InvalidPubkey {},
+
+    #[error("No voucher signer set")]
+    NoVoucherSigner {},
+
+    #[error("Voucher is not valid for this chain or contract")]
+    InvalidVoucherDomain {},
+
+    #[error("Voucher has expired")]
+    VoucherExpired {},
+
+    #[error("Voucher has already been redeemed")]
+    VoucherAlreadyRedeemed {},
+
+    #[error("Sender is not allowed to redeem this voucher")]
+    VoucherRecipientNotAllowed {},
+
+    #[error("Invalid voucher signature")]
+    InvalidVoucherSignature {},
+
+    #[error("Invalid voucher payment, expected {expected}")]
+    InvalidVoucherPayment { expected: String },
+
+    #[error("No merkle root set")]
+    NoMerkleRoot {},
+
+    #[error("Merkle root must be a 32 bytes sha256 hash")]
+    InvalidMerkleRoot {},
+
+    #[error("Invalid merkle proof")]
+    InvalidMerkleProof {},
+
+    #[error("Leaf has already been claimed")]
+    LeafAlreadyClaimed {},
+
+    #[error("Hook not found: {contract_addr}")]
+    HookNotFound { contract_addr: String },
+
+    #[error("Unknown reply id: {id}")]
+    UnknownReplyId { id: u64 },
+
     #[error("Approval not found for: {spender}")]
     ApprovalNotFound { spender: String },
 
@@ -78,4 +201,7 @@ pub enum Cw721ContractError {
 
     #[error("Internal error. Missing argument: Info")]
     NoInfo,
+
+    #[error("Legacy NFT info migration in progress, migrate again to complete it")]
+    LegacyMigrationInProgress {},
 }
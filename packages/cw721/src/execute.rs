@@ -1,26 +1,41 @@
 use cosmwasm_std::{
-    Addr, Api, BankMsg, Binary, Coin, CustomMsg, Deps, DepsMut, Empty, Env, MessageInfo, Response,
-    StdResult, Storage,
+    to_json_binary, Addr, Api, BankMsg, Binary, Coin, CustomMsg, Deps, DepsMut, Empty, Env, Event,
+    HexBinary, MessageInfo, Order, Reply, Response, StdResult, Storage, SubMsg, SubMsgResult,
+    Timestamp,
 };
 use cw_ownable::{Action, Ownership, OwnershipError};
-use cw_storage_plus::Item;
-use cw_utils::Expiration;
+use cw_storage_plus::{Bound, Item, Map};
+use cw_utils::{must_pay, nonpayable, Expiration};
+
+use url::Url;
 
 use crate::{
     error::Cw721ContractError,
     extension::{
         Cw721BaseExtensions, Cw721EmptyExtensions, Cw721Extensions, Cw721OnchainExtensions,
     },
-    helpers::value_or_empty,
-    msg::{CollectionInfoMsg, Cw721InstantiateMsg, Cw721MigrateMsg, NftInfoMsg},
+    helpers::{
+        claim_leaf, permit_hash, pubkey_to_canonical_addr, reveal_commitment, value_or_empty,
+        verify_merkle_proof, voucher_hash,
+    },
+    hook::{Cw721HookMsg, HOOK_REPLY_ID},
+    msg::{
+        BatchMintResponse, CollectionInfoMsg, Cw721InstantiateMsg, Cw721MigrateMsg, MintMsg,
+        MintResponse, MintVoucher, NftInfoMsg, Permit, PermitAction,
+    },
     query::query_collection_info_and_extension,
     receiver::Cw721ReceiveMsg,
-    state::{CollectionInfo, Cw721Config, NftInfo, CREATOR, MINTER},
+    state::{
+        BaseUri, BurnInfo, Cw721Config, HookOnError, LegacyNftInfo, NftInfo, NftMetadata,
+        NftOwnerInfo, NftProvenance, NftUser, OperatorFilterMode, PauseScope, PauseStatus,
+        RevealState, TokenHistoryAction, TokenHistoryEntry, TokenIdStrategy, TransferOffer,
+        ATTRIBUTE_START_TRADING_TIME, CREATOR, MINTER, PAUSER,
+    },
     traits::{
         Cw721CustomMsg, Cw721Execute, Cw721State, FromAttributesState, StateFactory,
         ToAttributesState,
     },
-    Approval, DefaultOptionalCollectionExtension, DefaultOptionalCollectionExtensionMsg,
+    DefaultOptionalCollectionExtension, DefaultOptionalCollectionExtensionMsg,
     DefaultOptionalNftExtension, DefaultOptionalNftExtensionMsg, EmptyOptionalCollectionExtension,
     EmptyOptionalCollectionExtensionMsg, EmptyOptionalNftExtension, EmptyOptionalNftExtensionMsg,
 };
@@ -86,6 +101,43 @@ where
         None => info.sender.as_str(),
     };
     initialize_creator(deps.storage, deps.api, Some(creator))?;
+    // pauser is initialized with creator, and can be transferred separately
+    initialize_pauser(deps.storage, deps.api, Some(creator))?;
+
+    // balances are maintained from the start
+    config.balances_migrated.save(deps.storage, &true)?;
+
+    if msg.enable_snapshots.unwrap_or(false) {
+        config.snapshots_enabled.save(deps.storage, &true)?;
+    }
+
+    if msg.allow_burned_remint.unwrap_or(false) {
+        config.allow_burned_remint.save(deps.storage, &true)?;
+    }
+
+    if let Some(token_history_depth) = msg.token_history_depth {
+        config
+            .token_history_depth
+            .save(deps.storage, &token_history_depth)?;
+    }
+
+    if let Some(token_id_strategy) = msg.token_id_strategy {
+        config
+            .token_id_strategy
+            .save(deps.storage, &token_id_strategy)?;
+    }
+
+    if let Some(max_supply) = msg.max_supply {
+        config.max_supply.save(deps.storage, &max_supply)?;
+    }
+
+    if let Some(base_uri) = msg.base_uri {
+        save_base_uri(deps.storage, Some(base_uri))?;
+    }
+
+    if let Some(reveal) = msg.reveal {
+        save_reveal_commitment(deps.storage, reveal.commitment, reveal.placeholder_uri)?;
+    }
 
     if let Some(withdraw_address) = msg.withdraw_address.clone() {
         let creator = deps.api.addr_validate(creator)?;
@@ -114,29 +166,83 @@ pub fn initialize_minter(
     MINTER.initialize_owner(storage, api, minter)
 }
 
-pub fn transfer_nft<TNftExtension>(
+pub fn initialize_pauser(
+    storage: &mut dyn Storage,
+    api: &dyn Api,
+    pauser: Option<&str>,
+) -> StdResult<Ownership<Addr>> {
+    PAUSER.initialize_owner(storage, api, pauser)
+}
+
+/// NOTE: only the lightweight ownership record is loaded and saved, NFT metadata is not affected.
+pub fn transfer_nft(
     deps: DepsMut,
     env: &Env,
     info: &MessageInfo,
     recipient: &str,
     token_id: &str,
-) -> Result<NftInfo<TNftExtension>, Cw721ContractError>
-where
-    TNftExtension: Cw721State,
-{
-    let config = Cw721Config::<TNftExtension>::default();
-    let mut token = config.nft_info.load(deps.storage, token_id)?;
+) -> Result<NftOwnerInfo, Cw721ContractError> {
+    move_nft(
+        deps,
+        env,
+        info,
+        recipient,
+        token_id,
+        TokenHistoryAction::Transfer,
+    )
+    .map(|(_, token)| token)
+}
+
+/// Moves the NFT to the recipient, used by transfers and sends. Given action is recorded in token history.
+/// Returns the previous owner and the updated ownership record.
+pub fn move_nft(
+    deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    recipient: &str,
+    token_id: &str,
+    action: TokenHistoryAction,
+) -> Result<(Addr, NftOwnerInfo), Cw721ContractError> {
+    let config = Cw721Config::<Option<Empty>>::default();
+    let mut token = config.nft_owner_info.load(deps.storage, token_id)?;
     // ensure we have permissions
-    check_can_send(deps.as_ref(), env, info.sender.as_str(), &token)?;
-    // set owner and remove existing approvals
+    check_can_send(deps.as_ref(), env, info.sender.as_str(), token_id, &token)?;
+    assert_not_paused(deps.storage, PauseScope::Transfers)?;
+    assert_trading_started(deps.as_ref(), env, &info.sender)?;
+    assert_not_locked(deps.as_ref(), token_id)?;
+    // set owner and remove existing approvals and user
+    token.user = None;
+    token.provenance.last_transferred_at = Some(env.block.time);
+    let previous_owner = token.owner.clone();
+    config.decrement_balance(deps.storage, &previous_owner)?;
     token.owner = deps.api.addr_validate(recipient)?;
-    token.approvals = vec![];
-    config.nft_info.save(deps.storage, token_id, &token)?;
-    Ok(token)
+    config.increment_balance(deps.storage, &token.owner)?;
+    config.snapshot_ownership(
+        deps.storage,
+        env.block.height,
+        token_id,
+        Some(&previous_owner),
+        Some(&token.owner),
+    )?;
+    config.record_token_history(
+        deps.storage,
+        token_id,
+        &TokenHistoryEntry {
+            action,
+            from: Some(previous_owner.clone()),
+            to: Some(token.owner.clone()),
+            height: env.block.height,
+            time: env.block.time,
+        },
+    )?;
+    config.nft_owner_info.save(deps.storage, token_id, &token)?;
+    config.transfer_offers.remove(deps.storage, token_id)?;
+    config.clear_token_approvals(deps.storage, token_id)?;
+    Ok((previous_owner, token))
 }
 
-pub fn send_nft<TNftExtension, TCustomResponseMsg>(
-    deps: DepsMut,
+pub fn send_nft<TCustomResponseMsg>(
+    mut deps: DepsMut,
     env: &Env,
     info: &MessageInfo,
     contract: String,
@@ -144,11 +250,25 @@ pub fn send_nft<TNftExtension, TCustomResponseMsg>(
     msg: Binary,
 ) -> Result<Response<TCustomResponseMsg>, Cw721ContractError>
 where
-    TNftExtension: Cw721State,
     TCustomResponseMsg: CustomMsg,
 {
+    assert_operator_allowed(deps.as_ref(), &contract)?;
     // Transfer token
-    transfer_nft::<TNftExtension>(deps, env, info, &contract, &token_id)?;
+    let (previous_owner, token) = move_nft(
+        deps.branch(),
+        env,
+        info,
+        &contract,
+        &token_id,
+        TokenHistoryAction::Send,
+    )?;
+    let hooks = hook_submsgs(
+        deps.storage,
+        TokenHistoryAction::Send,
+        &token_id,
+        Some(&previous_owner),
+        Some(&token.owner),
+    )?;
 
     let send = Cw721ReceiveMsg {
         sender: info.sender.to_string(),
@@ -159,13 +279,99 @@ where
     // Send message
     Ok(Response::new()
         .add_message(send.into_cosmos_msg(contract.clone())?)
+        .add_submessages(hooks)
         .add_attribute("action", "send_nft")
         .add_attribute("sender", info.sender.to_string())
         .add_attribute("recipient", contract)
         .add_attribute("token_id", token_id))
 }
 
-pub fn approve<TNftExtension, TCustomResponseMsg>(
+pub fn batch_transfer_nft<TCustomResponseMsg>(
+    mut deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    recipient: String,
+    token_ids: Vec<String>,
+) -> Result<Response<TCustomResponseMsg>, Cw721ContractError>
+where
+    TCustomResponseMsg: CustomMsg,
+{
+    let mut events = Vec::with_capacity(token_ids.len());
+    let mut hooks = vec![];
+    for token_id in token_ids {
+        let (previous_owner, token) = move_nft(
+            deps.branch(),
+            env,
+            info,
+            &recipient,
+            &token_id,
+            TokenHistoryAction::Transfer,
+        )?;
+        hooks.extend(hook_submsgs(
+            deps.storage,
+            TokenHistoryAction::Transfer,
+            &token_id,
+            Some(&previous_owner),
+            Some(&token.owner),
+        )?);
+        events.push(Event::new("transfer_nft").add_attribute("token_id", token_id));
+    }
+
+    Ok(Response::new()
+        .add_submessages(hooks)
+        .add_events(events)
+        .add_attribute("action", "batch_transfer_nft")
+        .add_attribute("sender", info.sender.to_string())
+        .add_attribute("recipient", recipient))
+}
+
+pub fn batch_send_nft<TCustomResponseMsg>(
+    mut deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    contract: String,
+    token_ids: Vec<String>,
+    msg: Binary,
+) -> Result<Response<TCustomResponseMsg>, Cw721ContractError>
+where
+    TCustomResponseMsg: CustomMsg,
+{
+    assert_operator_allowed(deps.as_ref(), &contract)?;
+    let mut response = Response::new();
+    for token_id in token_ids {
+        let (previous_owner, token) = move_nft(
+            deps.branch(),
+            env,
+            info,
+            &contract,
+            &token_id,
+            TokenHistoryAction::Send,
+        )?;
+        let hooks = hook_submsgs(
+            deps.storage,
+            TokenHistoryAction::Send,
+            &token_id,
+            Some(&previous_owner),
+            Some(&token.owner),
+        )?;
+        let send = Cw721ReceiveMsg {
+            sender: info.sender.to_string(),
+            token_id: token_id.clone(),
+            msg: msg.clone(),
+        };
+        response = response
+            .add_message(send.into_cosmos_msg(contract.clone())?)
+            .add_submessages(hooks)
+            .add_event(Event::new("send_nft").add_attribute("token_id", token_id));
+    }
+
+    Ok(response
+        .add_attribute("action", "batch_send_nft")
+        .add_attribute("sender", info.sender.to_string())
+        .add_attribute("recipient", contract))
+}
+
+pub fn approve<TCustomResponseMsg>(
     deps: DepsMut,
     env: &Env,
     info: &MessageInfo,
@@ -174,10 +380,11 @@ pub fn approve<TNftExtension, TCustomResponseMsg>(
     expires: Option<Expiration>,
 ) -> Result<Response<TCustomResponseMsg>, Cw721ContractError>
 where
-    TNftExtension: Cw721State,
     TCustomResponseMsg: CustomMsg,
 {
-    update_approvals::<TNftExtension>(deps, env, info, &spender, &token_id, true, expires)?;
+    assert_not_paused(deps.storage, PauseScope::Approvals)?;
+    assert_trading_started(deps.as_ref(), env, &info.sender)?;
+    update_approvals(deps, env, info, &spender, &token_id, true, expires)?;
 
     Ok(Response::new()
         .add_attribute("action", "approve")
@@ -187,7 +394,7 @@ where
 }
 
 #[allow(clippy::too_many_arguments)]
-pub fn update_approvals<TNftExtension>(
+pub fn update_approvals(
     deps: DepsMut,
     env: &Env,
     info: &MessageInfo,
@@ -196,49 +403,42 @@ pub fn update_approvals<TNftExtension>(
     // if add == false, remove. if add == true, remove then set with this expiration
     add: bool,
     expires: Option<Expiration>,
-) -> Result<NftInfo<TNftExtension>, Cw721ContractError>
-where
-    TNftExtension: Cw721State,
-{
-    let config = Cw721Config::<TNftExtension>::default();
-    let mut token = config.nft_info.load(deps.storage, token_id)?;
+) -> Result<NftOwnerInfo, Cw721ContractError> {
+    let config = Cw721Config::<Option<Empty>>::default();
+    let token = config.nft_owner_info.load(deps.storage, token_id)?;
     // ensure we have permissions
     check_can_approve(deps.as_ref(), env, info.sender.as_str(), &token)?;
 
-    // update the approval list (remove any for the same spender before adding)
     let spender_addr = deps.api.addr_validate(spender)?;
-    token.approvals.retain(|apr| apr.spender != spender_addr);
-
     // only difference between approve and revoke
     if add {
+        assert_operator_allowed(deps.as_ref(), spender)?;
         // reject expired data as invalid
         let expires = expires.unwrap_or_default();
         if expires.is_expired(&env.block) {
             return Err(Cw721ContractError::Expired {});
         }
-        let approval = Approval {
-            spender: spender_addr,
-            expires,
-        };
-        token.approvals.push(approval);
+        // overwrites any existing approval for the same spender
+        config
+            .token_approvals
+            .save(deps.storage, (token_id, &spender_addr), &expires)?;
+    } else {
+        config
+            .token_approvals
+            .remove(deps.storage, (token_id, &spender_addr));
     }
 
-    config.nft_info.save(deps.storage, token_id, &token)?;
-
     Ok(token)
 }
 
-pub fn revoke<TNftExtension, TCustomResponseMsg>(
+pub fn revoke<TCustomResponseMsg>(
     deps: DepsMut,
     env: &Env,
     info: &MessageInfo,
     spender: String,
     token_id: String,
-) -> Result<Response<TCustomResponseMsg>, Cw721ContractError>
-where
-    TNftExtension: Cw721State,
-{
-    update_approvals::<TNftExtension>(deps, env, info, &spender, &token_id, false, None)?;
+) -> Result<Response<TCustomResponseMsg>, Cw721ContractError> {
+    update_approvals(deps, env, info, &spender, &token_id, false, None)?;
 
     Ok(Response::new()
         .add_attribute("action", "revoke")
@@ -254,6 +454,8 @@ pub fn approve_all<TCustomResponseMsg>(
     operator: String,
     expires: Option<Expiration>,
 ) -> Result<Response<TCustomResponseMsg>, Cw721ContractError> {
+    assert_not_paused(deps.storage, PauseScope::Approvals)?;
+    assert_trading_started(deps.as_ref(), env, &info.sender)?;
     // reject expired data as invalid
     let expires = expires.unwrap_or_default();
     if expires.is_expired(&env.block) {
@@ -261,6 +463,7 @@ pub fn approve_all<TCustomResponseMsg>(
     }
 
     // set the operator for us
+    assert_operator_allowed(deps.as_ref(), &operator)?;
     let operator_addr = deps.api.addr_validate(&operator)?;
     let config = Cw721Config::<Option<Empty>>::default();
     config
@@ -293,25 +496,633 @@ pub fn revoke_all<TCustomResponseMsg>(
         .add_attribute("operator", operator))
 }
 
-pub fn burn_nft<TCustomResponseMsg>(
+/// Locks an NFT, so it cannot be transferred, sent or burned until the locker unlocks it.
+/// Owner keeps custody, so e.g. a staking contract doesn't need to take ownership.
+pub fn lock<TCustomResponseMsg>(
+    deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    token_id: String,
+) -> Result<Response<TCustomResponseMsg>, Cw721ContractError> {
+    let config = Cw721Config::<Option<Empty>>::default();
+    let token = config.nft_owner_info.load(deps.storage, &token_id)?;
+    // only owner, approved spender or operator can lock
+    check_can_send(deps.as_ref(), env, info.sender.as_str(), &token_id, &token)?;
+    assert_not_locked(deps.as_ref(), &token_id)?;
+    config
+        .token_locks
+        .save(deps.storage, &token_id, &info.sender)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "lock")
+        .add_attribute("sender", info.sender.to_string())
+        .add_attribute("token_id", token_id))
+}
+
+/// Unlocks an NFT, only the locker is allowed to do this.
+pub fn unlock<TCustomResponseMsg>(
+    deps: DepsMut,
+    _env: &Env,
+    info: &MessageInfo,
+    token_id: String,
+) -> Result<Response<TCustomResponseMsg>, Cw721ContractError> {
+    let config = Cw721Config::<Option<Empty>>::default();
+    let locker = config
+        .token_locks
+        .may_load(deps.storage, &token_id)?
+        .ok_or_else(|| Cw721ContractError::TokenNotLocked {
+            token_id: token_id.clone(),
+        })?;
+    if locker != info.sender {
+        return Err(Cw721ContractError::Ownership(OwnershipError::NotOwner));
+    }
+    config.token_locks.remove(deps.storage, &token_id)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "unlock")
+        .add_attribute("sender", info.sender.to_string())
+        .add_attribute("token_id", token_id))
+}
+
+/// Offers an NFT to a recipient. NFT stays with the owner until the recipient accepts it.
+/// An existing offer for the NFT is replaced.
+pub fn offer_transfer<TCustomResponseMsg>(
+    deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    token_id: String,
+    recipient: String,
+    expires: Option<Expiration>,
+) -> Result<Response<TCustomResponseMsg>, Cw721ContractError> {
+    let config = Cw721Config::<Option<Empty>>::default();
+    let token = config.nft_owner_info.load(deps.storage, &token_id)?;
+    // only owner, approved spender or operator can offer
+    check_can_send(deps.as_ref(), env, info.sender.as_str(), &token_id, &token)?;
+    assert_not_locked(deps.as_ref(), &token_id)?;
+    let expires = expires.unwrap_or_default();
+    if expires.is_expired(&env.block) {
+        return Err(Cw721ContractError::Expired {});
+    }
+    let offer = TransferOffer {
+        offered_by: info.sender.clone(),
+        recipient: deps.api.addr_validate(&recipient)?,
+        expires,
+    };
+    config
+        .transfer_offers
+        .save(deps.storage, &token_id, &offer)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "offer_transfer")
+        .add_attribute("sender", info.sender.to_string())
+        .add_attribute("recipient", recipient)
+        .add_attribute("token_id", token_id))
+}
+
+/// Accepts a pending transfer offer, only the recipient is allowed to do this.
+/// Permissions of the offerer are checked again, as if the offerer transferred the NFT.
+pub fn accept_transfer<TCustomResponseMsg>(
+    mut deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    token_id: String,
+) -> Result<Response<TCustomResponseMsg>, Cw721ContractError>
+where
+    TCustomResponseMsg: CustomMsg,
+{
+    let config = Cw721Config::<Option<Empty>>::default();
+    let offer = config
+        .transfer_offers
+        .may_load(deps.storage, &token_id)?
+        .ok_or_else(|| Cw721ContractError::NoTransferOffer {
+            token_id: token_id.clone(),
+        })?;
+    if offer.recipient != info.sender {
+        return Err(Cw721ContractError::NotTransferOfferRecipient {});
+    }
+    if offer.expires.is_expired(&env.block) {
+        return Err(Cw721ContractError::TransferOfferExpired { token_id });
+    }
+    let offerer_info = MessageInfo {
+        sender: offer.offered_by.clone(),
+        funds: vec![],
+    };
+    let (previous_owner, token) = move_nft(
+        deps.branch(),
+        env,
+        &offerer_info,
+        offer.recipient.as_str(),
+        &token_id,
+        TokenHistoryAction::Transfer,
+    )?;
+    let hooks = hook_submsgs(
+        deps.storage,
+        TokenHistoryAction::Transfer,
+        &token_id,
+        Some(&previous_owner),
+        Some(&token.owner),
+    )?;
+
+    Ok(Response::new()
+        .add_submessages(hooks)
+        .add_attribute("action", "accept_transfer")
+        .add_attribute("sender", info.sender.to_string())
+        .add_attribute("offered_by", offer.offered_by.to_string())
+        .add_attribute("token_id", token_id))
+}
+
+/// Cancels a pending transfer offer. Offerer, owner, approved spender or operator can cancel.
+pub fn cancel_transfer_offer<TCustomResponseMsg>(
+    deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    token_id: String,
+) -> Result<Response<TCustomResponseMsg>, Cw721ContractError> {
+    let config = Cw721Config::<Option<Empty>>::default();
+    let offer = config
+        .transfer_offers
+        .may_load(deps.storage, &token_id)?
+        .ok_or_else(|| Cw721ContractError::NoTransferOffer {
+            token_id: token_id.clone(),
+        })?;
+    if offer.offered_by != info.sender {
+        let token = config.nft_owner_info.load(deps.storage, &token_id)?;
+        check_can_send(deps.as_ref(), env, info.sender.as_str(), &token_id, &token)?;
+    }
+    config.transfer_offers.remove(deps.storage, &token_id)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "cancel_transfer_offer")
+        .add_attribute("sender", info.sender.to_string())
+        .add_attribute("token_id", token_id))
+}
+
+/// Approves the permit spender on behalf of the NFT owner, who signed the permit off-chain.
+pub fn permit_approve<TCustomResponseMsg>(
+    mut deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    permit: Permit,
+    pubkey: Binary,
+    signature: Binary,
+) -> Result<Response<TCustomResponseMsg>, Cw721ContractError>
+where
+    TCustomResponseMsg: CustomMsg,
+{
+    let owner = verify_permit(
+        deps.branch(),
+        env,
+        PermitAction::Approve,
+        &permit,
+        &pubkey,
+        &signature,
+    )?;
+    let owner_info = MessageInfo {
+        sender: owner.clone(),
+        funds: vec![],
+    };
+    approve::<TCustomResponseMsg>(
+        deps,
+        env,
+        &owner_info,
+        permit.spender.clone(),
+        permit.token_id.clone(),
+        Some(permit.expires),
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "permit_approve")
+        .add_attribute("sender", info.sender.to_string())
+        .add_attribute("owner", owner.to_string())
+        .add_attribute("spender", permit.spender)
+        .add_attribute("token_id", permit.token_id))
+}
+
+/// Transfers the NFT to the permit spender on behalf of the NFT owner, who signed the permit off-chain.
+pub fn permit_transfer<TCustomResponseMsg>(
+    mut deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    permit: Permit,
+    pubkey: Binary,
+    signature: Binary,
+) -> Result<Response<TCustomResponseMsg>, Cw721ContractError>
+where
+    TCustomResponseMsg: CustomMsg,
+{
+    let owner = verify_permit(
+        deps.branch(),
+        env,
+        PermitAction::Transfer,
+        &permit,
+        &pubkey,
+        &signature,
+    )?;
+    let owner_info = MessageInfo {
+        sender: owner.clone(),
+        funds: vec![],
+    };
+    let (_, token) = move_nft(
+        deps.branch(),
+        env,
+        &owner_info,
+        &permit.spender,
+        &permit.token_id,
+        TokenHistoryAction::Transfer,
+    )?;
+    let hooks = hook_submsgs(
+        deps.storage,
+        TokenHistoryAction::Transfer,
+        &permit.token_id,
+        Some(&owner),
+        Some(&token.owner),
+    )?;
+
+    Ok(Response::new()
+        .add_submessages(hooks)
+        .add_attribute("action", "permit_transfer")
+        .add_attribute("sender", info.sender.to_string())
+        .add_attribute("owner", owner.to_string())
+        .add_attribute("recipient", permit.spender)
+        .add_attribute("token_id", permit.token_id))
+}
+
+/// Verifies the permit is signed by the current NFT owner and consumes the owner's nonce. Returns the owner.
+fn verify_permit(
+    deps: DepsMut,
+    env: &Env,
+    action: PermitAction,
+    permit: &Permit,
+    pubkey: &Binary,
+    signature: &Binary,
+) -> Result<Addr, Cw721ContractError> {
+    if permit.chain_id != env.block.chain_id
+        || permit.contract_address != env.contract.address.as_str()
+    {
+        return Err(Cw721ContractError::InvalidPermitDomain {});
+    }
+    if permit.expires.is_expired(&env.block) {
+        return Err(Cw721ContractError::PermitExpired {});
+    }
+    let config = Cw721Config::<Option<Empty>>::default();
+    let owner = config
+        .nft_owner_info
+        .load(deps.storage, &permit.token_id)?
+        .owner;
+    if deps.api.addr_canonicalize(owner.as_str())? != pubkey_to_canonical_addr(pubkey) {
+        return Err(Cw721ContractError::PermitPubkeyMismatch {});
+    }
+    let nonce = config
+        .permit_nonces
+        .may_load(deps.storage, &owner)?
+        .unwrap_or_default();
+    if permit.nonce != nonce {
+        return Err(Cw721ContractError::InvalidPermitNonce { expected: nonce });
+    }
+    let hash = permit_hash(action, permit)?;
+    if !deps
+        .api
+        .secp256k1_verify(&hash, signature, pubkey)
+        .unwrap_or(false)
+    {
+        return Err(Cw721ContractError::InvalidPermitSignature {});
+    }
+    config
+        .permit_nonces
+        .save(deps.storage, &owner, &(nonce + 1))?;
+    Ok(owner)
+}
+
+/// Sets the public key signing mint vouchers, or removes it in case `None` is passed.
+pub fn set_voucher_signer<TCustomResponseMsg>(
+    deps: DepsMut,
+    _env: &Env,
+    info: &MessageInfo,
+    pubkey: Option<Binary>,
+) -> Result<Response<TCustomResponseMsg>, Cw721ContractError> {
+    assert_minter(deps.storage, &info.sender)?;
+    let config = Cw721Config::<Option<Empty>>::default();
+    match &pubkey {
+        Some(pubkey) => {
+            // compressed or uncompressed secp256k1 public key
+            if !matches!(pubkey.len(), 33 | 65) {
+                return Err(Cw721ContractError::InvalidPubkey {});
+            }
+            config.voucher_signer.save(deps.storage, pubkey)?;
+        }
+        None => config.voucher_signer.remove(deps.storage),
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "set_voucher_signer")
+        .add_attribute("sender", info.sender.to_string())
+        .add_attribute(
+            "pubkey",
+            pubkey.map_or("none".to_string(), |pubkey| pubkey.to_base64()),
+        ))
+}
+
+/// Lazy minting: mints the NFT of a voucher signed by the voucher signer to the sender, on behalf of the minter.
+pub fn redeem_voucher<TNftExtension, TNftExtensionMsg, TCustomResponseMsg>(
+    deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    voucher: MintVoucher<TNftExtensionMsg>,
+    signature: Binary,
+) -> Result<Response<TCustomResponseMsg>, Cw721ContractError>
+where
+    TNftExtension: Cw721State,
+    TNftExtensionMsg: Cw721CustomMsg + StateFactory<TNftExtension>,
+    TCustomResponseMsg: CustomMsg,
+{
+    if voucher.chain_id != env.block.chain_id
+        || voucher.contract_address != env.contract.address.as_str()
+    {
+        return Err(Cw721ContractError::InvalidVoucherDomain {});
+    }
+    if voucher.expires.is_expired(&env.block) {
+        return Err(Cw721ContractError::VoucherExpired {});
+    }
+    if let Some(recipients) = &voucher.recipients {
+        if !recipients.iter().any(|r| r == info.sender.as_str()) {
+            return Err(Cw721ContractError::VoucherRecipientNotAllowed {});
+        }
+    }
+    let config = Cw721Config::<Option<Empty>>::default();
+    let hash = voucher_hash(&voucher)?;
+    if config.redeemed_vouchers.has(deps.storage, &hash) {
+        return Err(Cw721ContractError::VoucherAlreadyRedeemed {});
+    }
+    let signer = config
+        .voucher_signer
+        .may_load(deps.storage)?
+        .ok_or(Cw721ContractError::NoVoucherSigner {})?;
+    if !deps
+        .api
+        .secp256k1_verify(&hash, &signature, &signer)
+        .unwrap_or(false)
+    {
+        return Err(Cw721ContractError::InvalidVoucherSignature {});
+    }
+    match &voucher.price {
+        Some(price) => {
+            if must_pay(info, &price.denom)? != price.amount {
+                return Err(Cw721ContractError::InvalidVoucherPayment {
+                    expected: price.to_string(),
+                });
+            }
+        }
+        None => nonpayable(info)?,
+    }
+    config
+        .redeemed_vouchers
+        .save(deps.storage, &hash, &Empty {})?;
+
+    // mint through the regular path, on behalf of the minter
+    let minter_info = minter_info(deps.storage)?;
+    let res = mint::<TNftExtension, TNftExtensionMsg, TCustomResponseMsg>(
+        deps,
+        env,
+        &minter_info,
+        voucher.token_id,
+        info.sender.to_string(),
+        voucher.token_uri,
+        voucher.extension,
+    )?;
+    Ok(res
+        .add_attribute("redeemed_by", info.sender.to_string())
+        .add_attribute("voucher_hash", HexBinary::from(hash).to_hex()))
+}
+
+/// Sets the merkle root of the claim allowlist, or removes it in case `None` is passed.
+pub fn set_merkle_root<TCustomResponseMsg>(
+    deps: DepsMut,
+    _env: &Env,
+    info: &MessageInfo,
+    merkle_root: Option<HexBinary>,
+) -> Result<Response<TCustomResponseMsg>, Cw721ContractError> {
+    assert_minter(deps.storage, &info.sender)?;
+    let config = Cw721Config::<Option<Empty>>::default();
+    match &merkle_root {
+        Some(merkle_root) => {
+            if merkle_root.len() != 32 {
+                return Err(Cw721ContractError::InvalidMerkleRoot {});
+            }
+            config.merkle_root.save(deps.storage, merkle_root)?;
+        }
+        None => config.merkle_root.remove(deps.storage),
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "set_merkle_root")
+        .add_attribute("sender", info.sender.to_string())
+        .add_attribute(
+            "merkle_root",
+            merkle_root.map_or("none".to_string(), |root| root.to_hex()),
+        ))
+}
+
+/// Mints the NFT of a leaf in the claim merkle tree to the sender, on behalf of the minter.
+pub fn claim<TNftExtension, TNftExtensionMsg, TCustomResponseMsg>(
+    deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    token_id: String,
+    token_uri: Option<String>,
+    extension: TNftExtensionMsg,
+    proof: Vec<HexBinary>,
+) -> Result<Response<TCustomResponseMsg>, Cw721ContractError>
+where
+    TNftExtension: Cw721State,
+    TNftExtensionMsg: Cw721CustomMsg + StateFactory<TNftExtension>,
+    TCustomResponseMsg: CustomMsg,
+{
+    let config = Cw721Config::<Option<Empty>>::default();
+    let merkle_root = config
+        .merkle_root
+        .may_load(deps.storage)?
+        .ok_or(Cw721ContractError::NoMerkleRoot {})?;
+    let leaf = claim_leaf(
+        info.sender.as_str(),
+        &token_id,
+        token_uri.clone(),
+        extension.clone(),
+    )?;
+    if config.claimed_leaves.has(deps.storage, &leaf) {
+        return Err(Cw721ContractError::LeafAlreadyClaimed {});
+    }
+    if !verify_merkle_proof(&merkle_root, &leaf, &proof) {
+        return Err(Cw721ContractError::InvalidMerkleProof {});
+    }
+    config.claimed_leaves.save(deps.storage, &leaf, &Empty {})?;
+
+    // mint through the regular path, on behalf of the minter
+    let minter_info = minter_info(deps.storage)?;
+    let res = mint::<TNftExtension, TNftExtensionMsg, TCustomResponseMsg>(
+        deps,
+        env,
+        &minter_info,
+        token_id,
+        info.sender.to_string(),
+        token_uri,
+        extension,
+    )?;
+    Ok(res
+        .add_attribute("claimed_by", info.sender.to_string())
+        .add_attribute("leaf", leaf.to_hex()))
+}
+
+/// Returns message info of the minter, used for minting on behalf of the minter.
+fn minter_info(storage: &dyn Storage) -> Result<MessageInfo, Cw721ContractError> {
+    let minter = MINTER
+        .get_ownership(storage)?
+        .owner
+        .ok_or(Cw721ContractError::Ownership(OwnershipError::NoOwner))?;
+    Ok(MessageInfo {
+        sender: minter,
+        funds: vec![],
+    })
+}
+
+/// Sets user of an NFT (ERC-4907), or removes it in case `None` is passed.
+pub fn set_user<TCustomResponseMsg>(
     deps: DepsMut,
     env: &Env,
     info: &MessageInfo,
     token_id: String,
+    user: Option<String>,
+    expires: Option<Expiration>,
 ) -> Result<Response<TCustomResponseMsg>, Cw721ContractError> {
     let config = Cw721Config::<Option<Empty>>::default();
-    let token = config.nft_info.load(deps.storage, &token_id)?;
-    check_can_send(deps.as_ref(), env, info.sender.as_str(), &token)?;
+    let mut token = config.nft_owner_info.load(deps.storage, &token_id)?;
+    // ensure we have permissions
+    check_can_send(deps.as_ref(), env, info.sender.as_str(), &token_id, &token)?;
+    token.user = match user.as_deref() {
+        Some(user) => {
+            // reject expired data as invalid
+            let expires = expires.unwrap_or_default();
+            if expires.is_expired(&env.block) {
+                return Err(Cw721ContractError::Expired {});
+            }
+            Some(NftUser {
+                user: deps.api.addr_validate(user)?,
+                expires,
+            })
+        }
+        None => None,
+    };
+    config
+        .nft_owner_info
+        .save(deps.storage, &token_id, &token)?;
 
-    config.nft_info.remove(deps.storage, &token_id)?;
-    config.decrement_tokens(deps.storage)?;
+    Ok(Response::new()
+        .add_attribute("action", "set_user")
+        .add_attribute("sender", info.sender.to_string())
+        .add_attribute("token_id", token_id)
+        .add_attribute("user", user.unwrap_or_default()))
+}
+
+pub fn burn_nft<TCustomResponseMsg>(
+    mut deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    token_id: String,
+) -> Result<Response<TCustomResponseMsg>, Cw721ContractError>
+where
+    TCustomResponseMsg: CustomMsg,
+{
+    let token = burn_nft_info(deps.branch(), env, info, &token_id)?;
+    Cw721Config::<Option<Empty>>::default().decrement_tokens(deps.storage)?;
+    let hooks = hook_submsgs(
+        deps.storage,
+        TokenHistoryAction::Burn,
+        &token_id,
+        Some(&token.owner),
+        None,
+    )?;
 
     Ok(Response::new()
+        .add_submessages(hooks)
         .add_attribute("action", "burn")
         .add_attribute("sender", info.sender.to_string())
         .add_attribute("token_id", token_id))
 }
 
+pub fn batch_burn_nft<TCustomResponseMsg>(
+    mut deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    token_ids: Vec<String>,
+) -> Result<Response<TCustomResponseMsg>, Cw721ContractError>
+where
+    TCustomResponseMsg: CustomMsg,
+{
+    let mut events = Vec::with_capacity(token_ids.len());
+    let mut hooks = vec![];
+    for token_id in token_ids {
+        let token = burn_nft_info(deps.branch(), env, info, &token_id)?;
+        hooks.extend(hook_submsgs(
+            deps.storage,
+            TokenHistoryAction::Burn,
+            &token_id,
+            Some(&token.owner),
+            None,
+        )?);
+        events.push(Event::new("burn").add_attribute("token_id", token_id));
+    }
+    Cw721Config::<Option<Empty>>::default()
+        .decrement_tokens_by(deps.storage, events.len() as u64)?;
+
+    Ok(Response::new()
+        .add_submessages(hooks)
+        .add_events(events)
+        .add_attribute("action", "batch_burn")
+        .add_attribute("sender", info.sender.to_string()))
+}
+
+/// Removes the NFT and records a tombstone, but does not update the token count. This is up to the caller, so batch burns
+/// only update `num_tokens` once.
+pub fn burn_nft_info(
+    deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    token_id: &str,
+) -> Result<NftOwnerInfo, Cw721ContractError> {
+    let config = Cw721Config::<Option<Empty>>::default();
+    let token = config.nft_owner_info.load(deps.storage, token_id)?;
+    check_can_send(deps.as_ref(), env, info.sender.as_str(), token_id, &token)?;
+    assert_not_locked(deps.as_ref(), token_id)?;
+    config.remove_nft_info(deps.storage, token_id)?;
+    config.burned_tokens.save(
+        deps.storage,
+        token_id,
+        &BurnInfo {
+            burned_by: info.sender.clone(),
+            burned_at: env.block.time,
+        },
+    )?;
+    config.decrement_balance(deps.storage, &token.owner)?;
+    config.snapshot_ownership(
+        deps.storage,
+        env.block.height,
+        token_id,
+        Some(&token.owner),
+        None,
+    )?;
+    config.record_token_history(
+        deps.storage,
+        token_id,
+        &TokenHistoryEntry {
+            action: TokenHistoryAction::Burn,
+            from: Some(token.owner.clone()),
+            to: None,
+            height: env.block.height,
+            time: env.block.time,
+        },
+    )?;
+    Ok(token)
+}
+
 pub fn update_collection_info<TCollectionExtension, TCollectionExtensionMsg, TCustomResponseMsg>(
     deps: DepsMut,
     info: Option<&MessageInfo>,
@@ -323,7 +1134,11 @@ where
     TCollectionExtensionMsg: Cw721CustomMsg + StateFactory<TCollectionExtension>,
     TCustomResponseMsg: CustomMsg,
 {
+    assert_not_paused(deps.storage, PauseScope::Metadata)?;
     let config = Cw721Config::<Option<Empty>>::default();
+    if config.load_metadata_freeze(deps.storage)?.collection_info {
+        return Err(Cw721ContractError::CollectionInfoFrozen {});
+    }
     let current = query_collection_info_and_extension::<TCollectionExtension>(deps.as_ref())?;
     let collection_info = msg.create(deps.as_ref(), env, info, Some(&current))?;
     let extension_attributes = collection_info.extension.to_attributes_state()?;
@@ -346,7 +1161,7 @@ where
 
 #[allow(clippy::too_many_arguments)]
 pub fn mint<TNftExtension, TNftExtensionMsg, TCustomResponseMsg>(
-    deps: DepsMut,
+    mut deps: DepsMut,
     env: &Env,
     info: &MessageInfo,
     token_id: String,
@@ -355,49 +1170,404 @@ pub fn mint<TNftExtension, TNftExtensionMsg, TCustomResponseMsg>(
     extension: TNftExtensionMsg,
 ) -> Result<Response<TCustomResponseMsg>, Cw721ContractError>
 where
-    TNftExtension: Cw721State,
-    TNftExtensionMsg: Cw721CustomMsg + StateFactory<TNftExtension>,
+    TNftExtension: Cw721State,
+    TNftExtensionMsg: Cw721CustomMsg + StateFactory<TNftExtension>,
+    TCustomResponseMsg: CustomMsg,
+{
+    assert_supply_available(deps.storage, 1)?;
+    let token_id = resolve_token_id(deps.storage, token_id)?;
+    let token = mint_nft_info::<TNftExtension, TNftExtensionMsg>(
+        deps.branch(),
+        env,
+        info,
+        &token_id,
+        owner.clone(),
+        token_uri.clone(),
+        extension,
+    )?;
+    Cw721Config::<TNftExtension>::default().increment_tokens(deps.storage)?;
+    let hooks = hook_submsgs(
+        deps.storage,
+        TokenHistoryAction::Mint,
+        &token_id,
+        None,
+        Some(&token.owner),
+    )?;
+
+    let mut res = Response::new()
+        .add_submessages(hooks)
+        .set_data(to_json_binary(&MintResponse {
+            token_id: token_id.clone(),
+        })?)
+        .add_attribute("action", "mint")
+        .add_attribute("minter", info.sender.to_string())
+        .add_attribute("owner", owner)
+        .add_attribute("token_id", token_id);
+    if let Some(token_uri) = token_uri {
+        res = res.add_attribute("token_uri", value_or_empty(&token_uri));
+    }
+    Ok(res)
+}
+
+pub fn batch_mint<TNftExtension, TNftExtensionMsg, TCustomResponseMsg>(
+    mut deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    tokens: Vec<MintMsg<TNftExtensionMsg>>,
+) -> Result<Response<TCustomResponseMsg>, Cw721ContractError>
+where
+    TNftExtension: Cw721State,
+    TNftExtensionMsg: Cw721CustomMsg + StateFactory<TNftExtension>,
+    TCustomResponseMsg: CustomMsg,
+{
+    assert_supply_available(deps.storage, tokens.len() as u64)?;
+    let mut events = Vec::with_capacity(tokens.len());
+    let mut token_ids = Vec::with_capacity(tokens.len());
+    let mut hooks = vec![];
+    for MintMsg {
+        token_id,
+        owner,
+        token_uri,
+        extension,
+    } in tokens
+    {
+        let token_id = resolve_token_id(deps.storage, token_id)?;
+        let token = mint_nft_info::<TNftExtension, TNftExtensionMsg>(
+            deps.branch(),
+            env,
+            info,
+            &token_id,
+            owner.clone(),
+            token_uri.clone(),
+            extension,
+        )?;
+        hooks.extend(hook_submsgs(
+            deps.storage,
+            TokenHistoryAction::Mint,
+            &token_id,
+            None,
+            Some(&token.owner),
+        )?);
+        let mut event = Event::new("mint")
+            .add_attribute("owner", owner)
+            .add_attribute("token_id", token_id.clone());
+        if let Some(token_uri) = token_uri {
+            event = event.add_attribute("token_uri", value_or_empty(&token_uri));
+        }
+        events.push(event);
+        token_ids.push(token_id);
+    }
+    Cw721Config::<TNftExtension>::default()
+        .increment_tokens_by(deps.storage, events.len() as u64)?;
+
+    Ok(Response::new()
+        .add_submessages(hooks)
+        .set_data(to_json_binary(&BatchMintResponse { token_ids })?)
+        .add_events(events)
+        .add_attribute("action", "batch_mint")
+        .add_attribute("minter", info.sender.to_string()))
+}
+
+/// Creates and stores the NFT, but does not update the token count. This is up to the caller, so
+/// batch mints only update `num_tokens` once.
+pub fn mint_nft_info<TNftExtension, TNftExtensionMsg>(
+    deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    token_id: &str,
+    owner: String,
+    token_uri: Option<String>,
+    extension: TNftExtensionMsg,
+) -> Result<NftInfo<TNftExtension>, Cw721ContractError>
+where
+    TNftExtension: Cw721State,
+    TNftExtensionMsg: Cw721CustomMsg + StateFactory<TNftExtension>,
+{
+    // create the token
+    let token_msg = NftInfoMsg {
+        owner,
+        token_uri,
+        extension,
+    };
+    assert_not_paused(deps.storage, PauseScope::Minting)?;
+    let mut token = token_msg.create(deps.as_ref(), env, info.into(), None)?;
+    token.provenance = NftProvenance {
+        minted_by: Some(info.sender.clone()),
+        minted_at: Some(env.block.time),
+        last_transferred_at: None,
+    };
+    let config = Cw721Config::<TNftExtension>::default();
+    if config.nft_owner_info.has(deps.storage, token_id) {
+        return Err(Cw721ContractError::Claimed {});
+    }
+    if config.burned_tokens.has(deps.storage, token_id) {
+        if !config
+            .allow_burned_remint
+            .may_load(deps.storage)?
+            .unwrap_or(false)
+        {
+            return Err(Cw721ContractError::TokenBurned {
+                token_id: token_id.to_string(),
+            });
+        }
+        config.burned_tokens.remove(deps.storage, token_id);
+    }
+    config.save_nft_info(deps.storage, token_id, &token)?;
+    config.increment_minted(deps.storage)?;
+    config.increment_balance(deps.storage, &token.owner)?;
+    config.snapshot_ownership(
+        deps.storage,
+        env.block.height,
+        token_id,
+        None,
+        Some(&token.owner),
+    )?;
+    config.record_token_history(
+        deps.storage,
+        token_id,
+        &TokenHistoryEntry {
+            action: TokenHistoryAction::Mint,
+            from: None,
+            to: Some(token.owner.clone()),
+            height: env.block.height,
+            time: env.block.time,
+        },
+    )?;
+    Ok(token)
+}
+
+pub fn update_minter_ownership<TCustomResponseMsg>(
+    deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    action: Action,
+) -> Result<Response<TCustomResponseMsg>, Cw721ContractError> {
+    let ownership = MINTER.update_ownership(deps, &env.block, &info.sender, action)?;
+    Ok(Response::new()
+        .add_attribute("update_minter_ownership", info.sender.to_string())
+        .add_attributes(ownership.into_attributes()))
+}
+
+pub fn update_pauser_ownership<TCustomResponseMsg>(
+    deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    action: Action,
+) -> Result<Response<TCustomResponseMsg>, Cw721ContractError> {
+    let ownership = PAUSER.update_ownership(deps, &env.block, &info.sender, action)?;
+    Ok(Response::new()
+        .add_attribute("update_pauser_ownership", info.sender.to_string())
+        .add_attributes(ownership.into_attributes()))
+}
+
+/// Sets operator filter mode, or disables filter in case `None` is passed. Only creator is allowed to do this.
+pub fn set_operator_filter_mode<TCustomResponseMsg>(
+    deps: DepsMut,
+    _env: &Env,
+    info: &MessageInfo,
+    mode: Option<OperatorFilterMode>,
+) -> Result<Response<TCustomResponseMsg>, Cw721ContractError> {
+    assert_creator(deps.storage, &info.sender)?;
+    let config = Cw721Config::<Option<Empty>>::default();
+    let mode_attr = match &mode {
+        Some(OperatorFilterMode::Allowlist) => "allowlist",
+        Some(OperatorFilterMode::Blocklist) => "blocklist",
+        None => "disabled",
+    };
+    match mode {
+        Some(mode) => config.operator_filter_mode.save(deps.storage, &mode)?,
+        None => config.operator_filter_mode.remove(deps.storage),
+    }
+    Ok(Response::new()
+        .add_attribute("action", "set_operator_filter_mode")
+        .add_attribute("sender", info.sender.to_string())
+        .add_attribute("mode", mode_attr))
+}
+
+/// Adds operators to the operator filter. Only creator is allowed to do this.
+pub fn add_filtered_operators<TCustomResponseMsg>(
+    deps: DepsMut,
+    _env: &Env,
+    info: &MessageInfo,
+    operators: Vec<String>,
+) -> Result<Response<TCustomResponseMsg>, Cw721ContractError> {
+    assert_creator(deps.storage, &info.sender)?;
+    let config = Cw721Config::<Option<Empty>>::default();
+    for operator in &operators {
+        let operator = deps.api.addr_validate(operator)?;
+        config
+            .operator_filter
+            .save(deps.storage, &operator, &Empty {})?;
+    }
+    Ok(Response::new()
+        .add_attribute("action", "add_filtered_operators")
+        .add_attribute("sender", info.sender.to_string())
+        .add_attribute("operators", operators.join(",")))
+}
+
+/// Removes operators from the operator filter. Only creator is allowed to do this.
+pub fn remove_filtered_operators<TCustomResponseMsg>(
+    deps: DepsMut,
+    _env: &Env,
+    info: &MessageInfo,
+    operators: Vec<String>,
+) -> Result<Response<TCustomResponseMsg>, Cw721ContractError> {
+    assert_creator(deps.storage, &info.sender)?;
+    let config = Cw721Config::<Option<Empty>>::default();
+    for operator in &operators {
+        let operator = deps.api.addr_validate(operator)?;
+        config.operator_filter.remove(deps.storage, &operator);
+    }
+    Ok(Response::new()
+        .add_attribute("action", "remove_filtered_operators")
+        .add_attribute("sender", info.sender.to_string())
+        .add_attribute("operators", operators.join(",")))
+}
+
+/// Registers a hook, notified on mint, transfer, send and burn. Only creator is allowed to do this.
+pub fn add_hook<TCustomResponseMsg>(
+    deps: DepsMut,
+    _env: &Env,
+    info: &MessageInfo,
+    contract_addr: String,
+    on_error: HookOnError,
+) -> Result<Response<TCustomResponseMsg>, Cw721ContractError> {
+    assert_creator(deps.storage, &info.sender)?;
+    let hook = deps.api.addr_validate(&contract_addr)?;
+    Cw721Config::<Option<Empty>>::default()
+        .hooks
+        .save(deps.storage, &hook, &on_error)?;
+    Ok(Response::new()
+        .add_attribute("action", "add_hook")
+        .add_attribute("sender", info.sender.to_string())
+        .add_attribute("contract_addr", contract_addr))
+}
+
+/// Removes a registered hook. Only creator is allowed to do this.
+pub fn remove_hook<TCustomResponseMsg>(
+    deps: DepsMut,
+    _env: &Env,
+    info: &MessageInfo,
+    contract_addr: String,
+) -> Result<Response<TCustomResponseMsg>, Cw721ContractError> {
+    assert_creator(deps.storage, &info.sender)?;
+    let hook = deps.api.addr_validate(&contract_addr)?;
+    let config = Cw721Config::<Option<Empty>>::default();
+    if !config.hooks.has(deps.storage, &hook) {
+        return Err(Cw721ContractError::HookNotFound { contract_addr });
+    }
+    config.hooks.remove(deps.storage, &hook);
+    Ok(Response::new()
+        .add_attribute("action", "remove_hook")
+        .add_attribute("sender", info.sender.to_string())
+        .add_attribute("contract_addr", contract_addr))
+}
+
+/// Returns a submessage for each registered hook, notifying about the given ownership change.
+pub fn hook_submsgs<TCustomResponseMsg>(
+    storage: &dyn Storage,
+    action: TokenHistoryAction,
+    token_id: &str,
+    from: Option<&Addr>,
+    to: Option<&Addr>,
+) -> StdResult<Vec<SubMsg<TCustomResponseMsg>>>
+where
     TCustomResponseMsg: CustomMsg,
 {
-    // create the token
-    let token_msg = NftInfoMsg {
-        owner: owner.clone(),
-        approvals: vec![],
-        token_uri: token_uri.clone(),
-        extension,
+    let hook_msg = Cw721HookMsg {
+        token_id: token_id.to_string(),
+        from: from.map(|addr| addr.to_string()),
+        to: to.map(|addr| addr.to_string()),
+        action,
     };
-    let token = token_msg.create(deps.as_ref(), env, info.into(), None)?;
-    let config = Cw721Config::<TNftExtension>::default();
-    config
-        .nft_info
-        .update(deps.storage, &token_id, |old| match old {
-            Some(_) => Err(Cw721ContractError::Claimed {}),
-            None => Ok(token),
-        })?;
-
-    config.increment_tokens(deps.storage)?;
+    Cw721Config::<Option<Empty>>::default()
+        .hooks
+        .range(storage, None, None, Order::Ascending)
+        .map(|item| {
+            let (hook, on_error) = item?;
+            let msg = hook_msg.clone().into_cosmos_msg(hook)?;
+            Ok(match on_error {
+                HookOnError::Fail => SubMsg::new(msg),
+                HookOnError::Ignore => SubMsg::reply_on_error(msg, HOOK_REPLY_ID),
+            })
+        })
+        .collect()
+}
 
-    let mut res = Response::new()
-        .add_attribute("action", "mint")
-        .add_attribute("minter", info.sender.to_string())
-        .add_attribute("owner", owner)
-        .add_attribute("token_id", token_id);
-    if let Some(token_uri) = token_uri {
-        res = res.add_attribute("token_uri", value_or_empty(&token_uri));
+/// Handles replies of failed hooks ignoring errors, see `HookOnError::Ignore`.
+pub fn reply<TCustomResponseMsg>(
+    _deps: DepsMut,
+    _env: &Env,
+    msg: Reply,
+) -> Result<Response<TCustomResponseMsg>, Cw721ContractError> {
+    match msg.id {
+        HOOK_REPLY_ID => {
+            let error = match msg.result {
+                SubMsgResult::Err(error) => error,
+                SubMsgResult::Ok(_) => String::default(),
+            };
+            Ok(Response::new()
+                .add_attribute("action", "hook_failed")
+                .add_attribute("error", error))
+        }
+        id => Err(Cw721ContractError::UnknownReplyId { id }),
     }
-    Ok(res)
 }
 
-pub fn update_minter_ownership<TCustomResponseMsg>(
+/// Pauses given scopes, only pauser or creator is allowed to do this.
+pub fn pause<TCustomResponseMsg>(
     deps: DepsMut,
-    env: &Env,
+    _env: &Env,
     info: &MessageInfo,
-    action: Action,
+    scopes: Vec<PauseScope>,
 ) -> Result<Response<TCustomResponseMsg>, Cw721ContractError> {
-    let ownership = MINTER.update_ownership(deps, &env.block, &info.sender, action)?;
+    update_pause_status(deps, info, &scopes, true)?;
     Ok(Response::new()
-        .add_attribute("update_minter_ownership", info.sender.to_string())
-        .add_attributes(ownership.into_attributes()))
+        .add_attribute("action", "pause")
+        .add_attribute("sender", info.sender.to_string())
+        .add_attribute("scopes", join_scopes(&scopes)))
+}
+
+/// Unpauses given scopes, only pauser or creator is allowed to do this.
+pub fn unpause<TCustomResponseMsg>(
+    deps: DepsMut,
+    _env: &Env,
+    info: &MessageInfo,
+    scopes: Vec<PauseScope>,
+) -> Result<Response<TCustomResponseMsg>, Cw721ContractError> {
+    update_pause_status(deps, info, &scopes, false)?;
+    Ok(Response::new()
+        .add_attribute("action", "unpause")
+        .add_attribute("sender", info.sender.to_string())
+        .add_attribute("scopes", join_scopes(&scopes)))
+}
+
+fn update_pause_status(
+    deps: DepsMut,
+    info: &MessageInfo,
+    scopes: &[PauseScope],
+    paused: bool,
+) -> Result<PauseStatus, Cw721ContractError> {
+    if !PAUSER.is_owner(deps.storage, &info.sender)?
+        && !CREATOR.is_owner(deps.storage, &info.sender)?
+    {
+        return Err(Cw721ContractError::NotPauserOrCreator {});
+    }
+    let config = Cw721Config::<Option<Empty>>::default();
+    let mut pause_status = config.load_pause_status(deps.storage)?;
+    for scope in scopes {
+        pause_status.set_paused(scope, paused);
+    }
+    config.pause_status.save(deps.storage, &pause_status)?;
+    Ok(pause_status)
+}
+
+fn join_scopes(scopes: &[PauseScope]) -> String {
+    scopes
+        .iter()
+        .map(|scope| scope.to_string())
+        .collect::<Vec<String>>()
+        .join(",")
 }
 
 pub fn update_creator_ownership<TCustomResponseMsg>(
@@ -427,21 +1597,331 @@ where
     TNftExtensionMsg: Cw721CustomMsg + StateFactory<TNftExtension>,
     TCustomResponseMsg: CustomMsg,
 {
+    assert_not_paused(deps.storage, PauseScope::Metadata)?;
     let contract = Cw721Config::<TNftExtension>::default();
-    let current_nft_info = contract.nft_info.load(deps.storage, &token_id)?;
+    let current_nft_info = contract.load_nft_info(deps.storage, &token_id)?;
+    if contract.is_nft_info_frozen(deps.storage, &token_id)? {
+        return Err(Cw721ContractError::NftInfoFrozen { token_id });
+    }
     let nft_info_msg = NftInfoMsg {
         owner: current_nft_info.owner.to_string(),
-        approvals: current_nft_info.approvals.clone(),
         token_uri,
         extension: msg,
     };
     let updated = nft_info_msg.create(deps.as_ref(), env, info, Some(&current_nft_info))?;
-    contract.nft_info.save(deps.storage, &token_id, &updated)?;
+    // owner is unchanged, so only metadata is saved
+    contract.nft_metadata.save(
+        deps.storage,
+        &token_id,
+        &NftMetadata {
+            token_uri: updated.token_uri,
+            extension: updated.extension,
+        },
+    )?;
     Ok(Response::new()
         .add_attribute("action", "update_nft_info")
         .add_attribute("token_id", token_id))
 }
 
+/// Freezes token uri and onchain metadata of an NFT. Only creator is allowed to do this.
+/// A token uri derived from base uri is stored with the NFT, so later base uri changes don't
+/// affect it. NOTE: freezing is irreversible!
+pub fn freeze_nft_info<TNftExtension, TCustomResponseMsg>(
+    deps: DepsMut,
+    _env: &Env,
+    info: &MessageInfo,
+    token_id: String,
+) -> Result<Response<TCustomResponseMsg>, Cw721ContractError>
+where
+    TNftExtension: Cw721State,
+{
+    assert_creator(deps.storage, &info.sender)?;
+    let config = Cw721Config::<TNftExtension>::default();
+    // ensure token exists
+    config.nft_owner_info.load(deps.storage, &token_id)?;
+    let mut nft_metadata = config.nft_metadata.load(deps.storage, &token_id)?;
+    // placeholder uri is kept out, since token uri is derived on reveal
+    let reveal_pending = config
+        .reveal
+        .may_load(deps.storage)?
+        .is_some_and(|reveal| reveal.base_uri.is_none());
+    if nft_metadata.token_uri.is_none() && !reveal_pending {
+        nft_metadata.token_uri = config.resolve_token_uri(deps.storage, &token_id, None)?;
+        config
+            .nft_metadata
+            .save(deps.storage, &token_id, &nft_metadata)?;
+    }
+    config
+        .frozen_nft_info
+        .save(deps.storage, &token_id, &Empty {})?;
+    Ok(Response::new()
+        .add_attribute("action", "freeze_nft_info")
+        .add_attribute("sender", info.sender.to_string())
+        .add_attribute("token_id", token_id))
+}
+
+/// Freezes token uri and onchain metadata of all NFTs, including NFTs minted later on.
+/// Only creator is allowed to do this. NOTE: freezing is irreversible!
+pub fn freeze_all_nft_info<TCustomResponseMsg>(
+    deps: DepsMut,
+    _env: &Env,
+    info: &MessageInfo,
+) -> Result<Response<TCustomResponseMsg>, Cw721ContractError> {
+    assert_creator(deps.storage, &info.sender)?;
+    let config = Cw721Config::<Option<Empty>>::default();
+    let mut metadata_freeze = config.load_metadata_freeze(deps.storage)?;
+    metadata_freeze.all_nft_info = true;
+    config
+        .metadata_freeze
+        .save(deps.storage, &metadata_freeze)?;
+    Ok(Response::new()
+        .add_attribute("action", "freeze_all_nft_info")
+        .add_attribute("sender", info.sender.to_string()))
+}
+
+/// Freezes collection info and its extension. Only creator is allowed to do this.
+/// NOTE: freezing is irreversible!
+pub fn freeze_collection_info<TCustomResponseMsg>(
+    deps: DepsMut,
+    _env: &Env,
+    info: &MessageInfo,
+) -> Result<Response<TCustomResponseMsg>, Cw721ContractError> {
+    assert_creator(deps.storage, &info.sender)?;
+    let config = Cw721Config::<Option<Empty>>::default();
+    let mut metadata_freeze = config.load_metadata_freeze(deps.storage)?;
+    metadata_freeze.collection_info = true;
+    config
+        .metadata_freeze
+        .save(deps.storage, &metadata_freeze)?;
+    Ok(Response::new()
+        .add_attribute("action", "freeze_collection_info")
+        .add_attribute("sender", info.sender.to_string()))
+}
+
+/// Sets (or removes, in case of `None`) the base uri of the collection. NFTs without a token uri
+/// derive it from base uri, token id and suffix. Only creator is allowed to do this.
+/// NOTE: this affects all NFTs without token uri, so it is rejected once all NFT infos are frozen.
+pub fn set_base_uri<TCustomResponseMsg>(
+    deps: DepsMut,
+    _env: &Env,
+    info: &MessageInfo,
+    base_uri: Option<BaseUri>,
+) -> Result<Response<TCustomResponseMsg>, Cw721ContractError> {
+    assert_not_paused(deps.storage, PauseScope::Metadata)?;
+    assert_creator(deps.storage, &info.sender)?;
+    let metadata_freeze =
+        Cw721Config::<Option<Empty>>::default().load_metadata_freeze(deps.storage)?;
+    if metadata_freeze.base_uri || metadata_freeze.all_nft_info {
+        return Err(Cw721ContractError::BaseUriFrozen {});
+    }
+    let uri = base_uri
+        .as_ref()
+        .map(|base_uri| base_uri.token_uri("{token_id}"));
+    save_base_uri(deps.storage, base_uri)?;
+    Ok(Response::new()
+        .add_attribute("action", "set_base_uri")
+        .add_attribute("sender", info.sender.to_string())
+        .add_attribute("base_uri", uri.unwrap_or("none".to_string())))
+}
+
+fn save_base_uri(
+    storage: &mut dyn Storage,
+    base_uri: Option<BaseUri>,
+) -> Result<(), Cw721ContractError> {
+    let config = Cw721Config::<Option<Empty>>::default();
+    match base_uri {
+        Some(base_uri) => {
+            Url::parse(&base_uri.uri)?;
+            config.base_uri.save(storage, &base_uri)?;
+        }
+        None => config.base_uri.remove(storage),
+    }
+    Ok(())
+}
+
+/// Returns the token id to mint. An empty token id is replaced by a generated one, in case a
+/// token id strategy is configured. Otherwise the given token id is kept as is.
+pub fn resolve_token_id(
+    storage: &mut dyn Storage,
+    token_id: String,
+) -> Result<String, Cw721ContractError> {
+    if !token_id.is_empty() {
+        return Ok(token_id);
+    }
+    let config = Cw721Config::<Option<Empty>>::default();
+    match config.token_id_strategy.may_load(storage)? {
+        Some(strategy) => generate_token_id(storage, strategy),
+        None => Ok(token_id),
+    }
+}
+
+/// Generates the next token id based on given token id strategy.
+/// Token ids already in use or burned are skipped.
+pub fn generate_token_id(
+    storage: &mut dyn Storage,
+    strategy: TokenIdStrategy,
+) -> Result<String, Cw721ContractError> {
+    let config = Cw721Config::<Option<Empty>>::default();
+    match strategy {
+        TokenIdStrategy::Sequential { offset, padding } => {
+            let mut next = config.next_token_id.may_load(storage)?.unwrap_or(offset);
+            loop {
+                let token_id = format!(
+                    "{:0width$}",
+                    next,
+                    width = padding.unwrap_or_default() as usize
+                );
+                next += 1;
+                if !config.nft_owner_info.has(storage, &token_id)
+                    && !config.burned_tokens.has(storage, &token_id)
+                {
+                    config.next_token_id.save(storage, &next)?;
+                    return Ok(token_id);
+                }
+            }
+        }
+    }
+}
+
+/// Permanently disables minting. Only creator is allowed to do this.
+/// NOTE: sealing is irreversible!
+pub fn seal_supply<TCustomResponseMsg>(
+    deps: DepsMut,
+    _env: &Env,
+    info: &MessageInfo,
+) -> Result<Response<TCustomResponseMsg>, Cw721ContractError> {
+    assert_creator(deps.storage, &info.sender)?;
+    Cw721Config::<Option<Empty>>::default()
+        .supply_sealed
+        .save(deps.storage, &true)?;
+    Ok(Response::new()
+        .add_attribute("action", "seal_supply")
+        .add_attribute("sender", info.sender.to_string()))
+}
+
+/// Asserts that supply is not sealed, and minting given amount of NFTs does not exceed max supply.
+pub fn assert_supply_available(
+    storage: &dyn Storage,
+    amount: u64,
+) -> Result<(), Cw721ContractError> {
+    let config = Cw721Config::<Option<Empty>>::default();
+    if config.is_supply_sealed(storage)? {
+        return Err(Cw721ContractError::SupplySealed {});
+    }
+    if let Some(max_supply) = config.max_supply.may_load(storage)? {
+        let supply = if max_supply.include_burned {
+            config.minted_count(storage)?
+        } else {
+            config.token_count(storage)?
+        };
+        if supply + amount > max_supply.limit {
+            return Err(Cw721ContractError::MaxSupplyExceeded {
+                max_supply: max_supply.limit,
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Freezes base uri. Only creator is allowed to do this. NOTE: freezing is irreversible!
+pub fn freeze_base_uri<TCustomResponseMsg>(
+    deps: DepsMut,
+    _env: &Env,
+    info: &MessageInfo,
+) -> Result<Response<TCustomResponseMsg>, Cw721ContractError> {
+    assert_creator(deps.storage, &info.sender)?;
+    let config = Cw721Config::<Option<Empty>>::default();
+    let mut metadata_freeze = config.load_metadata_freeze(deps.storage)?;
+    metadata_freeze.base_uri = true;
+    config
+        .metadata_freeze
+        .save(deps.storage, &metadata_freeze)?;
+    Ok(Response::new()
+        .add_attribute("action", "freeze_base_uri")
+        .add_attribute("sender", info.sender.to_string()))
+}
+
+/// Commits hash of final base uri and salt. Until revealed, NFTs without own token
+/// uri return the placeholder uri.
+/// Only creator is allowed to do this, and only before any NFT is minted.
+pub fn commit_reveal<TCustomResponseMsg>(
+    deps: DepsMut,
+    _env: &Env,
+    info: &MessageInfo,
+    commitment: HexBinary,
+    placeholder_uri: String,
+) -> Result<Response<TCustomResponseMsg>, Cw721ContractError> {
+    assert_creator(deps.storage, &info.sender)?;
+    save_reveal_commitment(deps.storage, commitment.clone(), placeholder_uri.clone())?;
+    Ok(Response::new()
+        .add_attribute("action", "commit_reveal")
+        .add_attribute("sender", info.sender.to_string())
+        .add_attribute("commitment", commitment.to_hex())
+        .add_attribute("placeholder_uri", placeholder_uri))
+}
+
+fn save_reveal_commitment(
+    storage: &mut dyn Storage,
+    commitment: HexBinary,
+    placeholder_uri: String,
+) -> Result<(), Cw721ContractError> {
+    let config = Cw721Config::<Option<Empty>>::default();
+    if let Some(RevealState {
+        base_uri: Some(_), ..
+    }) = config.reveal.may_load(storage)?
+    {
+        return Err(Cw721ContractError::AlreadyRevealed {});
+    }
+    // burned NFTs count as minted, collections minted before `total_minted` was tracked are
+    // covered by the live token count
+    if config.minted_count(storage)? > 0 || config.token_count(storage)? > 0 {
+        return Err(Cw721ContractError::RevealCommitAfterMint {});
+    }
+    if commitment.len() != 32 {
+        return Err(Cw721ContractError::InvalidRevealCommitment {});
+    }
+    Url::parse(&placeholder_uri)?;
+    config.reveal.save(
+        storage,
+        &RevealState {
+            commitment,
+            placeholder_uri,
+            base_uri: None,
+        },
+    )?;
+    Ok(())
+}
+
+/// Reveals the final base uri of all NFTs. Base uri and salt must match the committed hash.
+/// Only creator is allowed to do this.
+pub fn reveal<TCustomResponseMsg>(
+    deps: DepsMut,
+    _env: &Env,
+    info: &MessageInfo,
+    base_uri: String,
+    salt: String,
+) -> Result<Response<TCustomResponseMsg>, Cw721ContractError> {
+    assert_creator(deps.storage, &info.sender)?;
+    let config = Cw721Config::<Option<Empty>>::default();
+    let mut reveal = config
+        .reveal
+        .may_load(deps.storage)?
+        .ok_or(Cw721ContractError::RevealNotCommitted {})?;
+    if reveal.base_uri.is_some() {
+        return Err(Cw721ContractError::AlreadyRevealed {});
+    }
+    if reveal_commitment(&base_uri, &salt) != reveal.commitment {
+        return Err(Cw721ContractError::InvalidRevealPreimage {});
+    }
+    Url::parse(&base_uri)?;
+    reveal.base_uri = Some(base_uri.clone());
+    config.reveal.save(deps.storage, &reveal)?;
+    Ok(Response::new()
+        .add_attribute("action", "reveal")
+        .add_attribute("sender", info.sender.to_string())
+        .add_attribute("base_uri", base_uri))
+}
+
 pub fn set_withdraw_address<TCustomResponseMsg>(
     deps: DepsMut,
     sender: &Addr,
@@ -498,22 +1978,19 @@ pub fn withdraw_funds<TCustomResponseMsg>(
 }
 
 /// returns true if the sender can execute approve or reject on the contract
-pub fn check_can_approve<TNftExtension>(
+pub fn check_can_approve(
     deps: Deps,
     env: &Env,
     sender: &str,
-    token: &NftInfo<TNftExtension>,
-) -> Result<(), Cw721ContractError>
-where
-    TNftExtension: Cw721State,
-{
+    token: &NftOwnerInfo,
+) -> Result<(), Cw721ContractError> {
     let sender = deps.api.addr_validate(sender)?;
     // owner can approve
     if token.owner == sender {
         return Ok(());
     }
     // operator can approve
-    let config = Cw721Config::<TNftExtension>::default();
+    let config = Cw721Config::<Option<Empty>>::default();
     let op = config
         .operators
         .may_load(deps.storage, (&token.owner, &sender))?;
@@ -530,11 +2007,12 @@ where
 }
 
 /// returns true if the sender can transfer ownership of the token
-pub fn check_can_send<TNftExtension>(
+pub fn check_can_send(
     deps: Deps,
     env: &Env,
     sender: &str,
-    token: &NftInfo<TNftExtension>,
+    token_id: &str,
+    token: &NftOwnerInfo,
 ) -> Result<(), Cw721ContractError> {
     let sender = deps.api.addr_validate(sender)?;
     // owner can send
@@ -543,16 +2021,15 @@ pub fn check_can_send<TNftExtension>(
     }
 
     // any non-expired token approval can send
-    if token
-        .approvals
-        .iter()
-        .any(|apr| apr.spender == sender && !apr.is_expired(&env.block))
-    {
-        return Ok(());
+    let config = Cw721Config::<Option<Empty>>::default();
+    let approval = config
+        .token_approvals
+        .may_load(deps.storage, (token_id, &sender))?;
+    if approval.is_some_and(|expires| !expires.is_expired(&env.block)) {
+        return assert_operator_allowed(deps, sender.as_str());
     }
 
     // operator can send
-    let config = Cw721Config::<Option<Empty>>::default();
     let op = config
         .operators
         // has token owner approved/gave grant to sender for full control over owner's NFTs?
@@ -563,7 +2040,7 @@ pub fn check_can_send<TNftExtension>(
             if ex.is_expired(&env.block) {
                 Err(Cw721ContractError::Ownership(OwnershipError::NotOwner))
             } else {
-                Ok(())
+                assert_operator_allowed(deps, sender.as_str())
             }
         }
         None => Err(Cw721ContractError::Ownership(OwnershipError::NotOwner)),
@@ -584,23 +2061,99 @@ pub fn assert_creator(storage: &dyn Storage, sender: &Addr) -> Result<(), Cw721C
     Ok(())
 }
 
+/// Until `start_trading_time` (if set in collection extension) is reached, only minter and creator
+/// can transfer, send and approve NFTs.
+pub fn assert_trading_started(
+    deps: Deps,
+    env: &Env,
+    sender: &Addr,
+) -> Result<(), Cw721ContractError> {
+    let config = Cw721Config::<Option<Empty>>::default();
+    let start_trading_time = config
+        .collection_extension
+        .may_load(deps.storage, ATTRIBUTE_START_TRADING_TIME.to_string())?
+        .map(|attr| attr.value::<Option<Timestamp>>())
+        .transpose()?
+        .flatten();
+    if let Some(start_trading_time) = start_trading_time {
+        if env.block.time < start_trading_time
+            && MINTER.assert_owner(deps.storage, sender).is_err()
+            && CREATOR.assert_owner(deps.storage, sender).is_err()
+        {
+            return Err(Cw721ContractError::TradingNotStarted { start_trading_time });
+        }
+    }
+    Ok(())
+}
+
+/// Checks operator (spender, operator or `SendNft` recipient) against the operator filter.
+pub fn assert_operator_allowed(deps: Deps, operator: &str) -> Result<(), Cw721ContractError> {
+    let operator = deps.api.addr_validate(operator)?;
+    if !Cw721Config::<Option<Empty>>::default().is_operator_allowed(deps.storage, &operator)? {
+        return Err(Cw721ContractError::OperatorNotAllowed {
+            operator: operator.to_string(),
+        });
+    }
+    Ok(())
+}
+
+pub fn assert_not_paused(
+    storage: &dyn Storage,
+    scope: PauseScope,
+) -> Result<(), Cw721ContractError> {
+    let pause_status = Cw721Config::<Option<Empty>>::default().load_pause_status(storage)?;
+    if pause_status.is_paused(&scope) {
+        return Err(Cw721ContractError::Paused { scope });
+    }
+    Ok(())
+}
+
+pub fn assert_not_locked(deps: Deps, token_id: &str) -> Result<(), Cw721ContractError> {
+    if Cw721Config::<Option<Empty>>::default()
+        .token_locks
+        .has(deps.storage, token_id)
+    {
+        return Err(Cw721ContractError::TokenLocked {
+            token_id: token_id.to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Asserts that no paginated legacy NFT info migration is in progress, since legacy NFTs
+/// not yet migrated would lose their metadata and approvals.
+pub fn assert_no_legacy_migration(storage: &dyn Storage) -> Result<(), Cw721ContractError> {
+    if Cw721Config::<Option<Empty>>::default()
+        .legacy_nft_info_cursor
+        .exists(storage)
+    {
+        return Err(Cw721ContractError::LegacyMigrationInProgress {});
+    }
+    Ok(())
+}
+
 // ------- migrate -------
-pub fn migrate(
+pub fn migrate<TNftExtension>(
     deps: DepsMut,
     env: Env,
     msg: Cw721MigrateMsg,
     contract_name: &str,
     contract_version: &str,
-) -> Result<Response, Cw721ContractError> {
+) -> Result<Response, Cw721ContractError>
+where
+    TNftExtension: Cw721State,
+{
     let response = Response::<Empty>::default();
     // first migrate legacy data ...
     let response = migrate_legacy_minter_and_creator(deps.storage, deps.api, &env, &msg, response)?;
     let response = migrate_legacy_collection_info(deps.storage, &env, &msg, response)?;
+    let response = migrate_legacy_nft_info::<TNftExtension>(deps.storage, &env, &msg, response)?;
     // ... then migrate
     let response = migrate_version(deps.storage, contract_name, contract_version, response)?;
     // ... and update creator and minter AFTER legacy migration
     let response = migrate_creator(deps.storage, deps.api, &env, &msg, response)?;
     let response = migrate_minter(deps.storage, deps.api, &env, &msg, response)?;
+    let response = migrate_pauser(deps.storage, deps.api, &env, &msg, response)?;
     Ok(response)
 }
 
@@ -655,6 +2208,26 @@ pub fn migrate_minter(
     Ok(response)
 }
 
+/// Initializes pauser with creator, in case pauser is not yet set.
+pub fn migrate_pauser(
+    storage: &mut dyn Storage,
+    api: &dyn Api,
+    _env: &Env,
+    _msg: &Cw721MigrateMsg,
+    response: Response,
+) -> StdResult<Response> {
+    if PAUSER.item.exists(storage) {
+        return Ok(response);
+    }
+    match CREATOR.get_ownership(storage)?.owner {
+        Some(creator) => {
+            PAUSER.initialize_owner(storage, api, Some(creator.as_str()))?;
+            Ok(response.add_attribute("pauser", creator))
+        }
+        None => Ok(response),
+    }
+}
+
 /// Migrates only in case ownership is not present
 /// !!! Important note here: !!!
 /// - creator owns the contract and can update collection info
@@ -704,30 +2277,124 @@ pub fn migrate_legacy_minter_and_creator(
 /// Migrates only in case collection_info is not present
 pub fn migrate_legacy_collection_info(
     storage: &mut dyn Storage,
-    env: &Env,
+    _env: &Env,
     _msg: &Cw721MigrateMsg,
     response: Response,
 ) -> Result<Response, Cw721ContractError> {
     let contract = Cw721Config::<Empty>::default();
     match contract.collection_info.may_load(storage)? {
         Some(_) => Ok(response),
+        None => Ok(response),
+    }
+}
+
+/// Max number of legacy NFTs migrated per migration, see `migrate_legacy_nft_info`.
+pub const LEGACY_NFT_INFO_MIGRATION_LIMIT: usize = 500;
+
+/// Migrates only NFTs, where metadata (and approvals) are still stored within the ownership record.
+/// For collections created before balances were tracked, per-owner balances are backfilled along
+/// the way. At most `LEGACY_NFT_INFO_MIGRATION_LIMIT` NFTs are processed at once, for larger
+/// collections migrate is called again until no `legacy_nft_info_cursor` attribute is returned.
+/// Meanwhile all executes are rejected, see `assert_no_legacy_migration`.
+pub fn migrate_legacy_nft_info<TNftExtension>(
+    storage: &mut dyn Storage,
+    _env: &Env,
+    _msg: &Cw721MigrateMsg,
+    response: Response,
+) -> Result<Response, Cw721ContractError>
+where
+    TNftExtension: Cw721State,
+{
+    let config = Cw721Config::<TNftExtension>::default();
+    let cursor = config.legacy_nft_info_cursor.may_load(storage)?;
+    let count_balances = !config.balances_migrated.may_load(storage)?.unwrap_or(false);
+    // already migrated, since metadata is stored separately and no migration is in progress
+    if cursor.is_none() && !config.nft_metadata.is_empty(storage) && !count_balances {
+        return Ok(response);
+    }
+    // legacy NFT info is stored with same key as `Cw721Config::nft_owner_info`
+    let legacy_nft_info: Map<&str, LegacyNftInfo<TNftExtension>> = Map::new("tokens");
+    let token_ids = legacy_nft_info
+        .keys(
+            storage,
+            cursor.as_deref().map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(LEGACY_NFT_INFO_MIGRATION_LIMIT)
+        .collect::<StdResult<Vec<String>>>()?;
+    // more NFTs may be left, continued by next migration
+    let cursor = match token_ids.last() {
+        Some(last_token_id) if token_ids.len() == LEGACY_NFT_INFO_MIGRATION_LIMIT => {
+            Some(last_token_id.clone())
+        }
+        _ => None,
+    };
+    let mut migrated = 0;
+    let mut counted = 0;
+    for token_id in token_ids {
+        // NFT is already migrated in case metadata is stored separately
+        if config.nft_metadata.has(storage, &token_id) {
+            if count_balances {
+                let NftOwnerInfo { owner, .. } = config.nft_owner_info.load(storage, &token_id)?;
+                config.increment_balance(storage, &owner)?;
+                counted += 1;
+            }
+            continue;
+        }
+        let legacy = legacy_nft_info.load(storage, &token_id)?;
+        if count_balances {
+            config.increment_balance(storage, &legacy.owner)?;
+            counted += 1;
+        }
+        for approval in legacy.approvals.unwrap_or_default() {
+            config.token_approvals.save(
+                storage,
+                (&token_id, &approval.spender),
+                &approval.expires,
+            )?;
+        }
+        config.nft_metadata.save(
+            storage,
+            &token_id,
+            &NftMetadata {
+                token_uri: legacy.token_uri,
+                extension: legacy.extension,
+            },
+        )?;
+        // owner is unchanged, so there is no need to update owner index
+        let nft_owner_info: Map<&str, NftOwnerInfo> = Map::new("tokens");
+        nft_owner_info.save(
+            storage,
+            &token_id,
+            &NftOwnerInfo {
+                owner: legacy.owner,
+                user: None,
+                provenance: NftProvenance::default(),
+            },
+        )?;
+        migrated += 1;
+    }
+    let response = match cursor {
+        Some(cursor) => {
+            config.legacy_nft_info_cursor.save(storage, &cursor)?;
+            response.add_attribute("legacy_nft_info_cursor", cursor)
+        }
         None => {
-            Ok(response)
-            // // contract info = legacy collection info
-            // let legacy_collection_info_store: Item<cw721_016::ContractInfoResponse> =
-            //     Item::new("nft_info");
-            // let legacy_collection_info = legacy_collection_info_store.load(storage)?;
-            // let collection_info = CollectionInfo {
-            //     name: legacy_collection_info.name.clone(),
-            //     symbol: legacy_collection_info.symbol.clone(),
-            //     updated_at: env.block.time,
-            // };
-            // contract.collection_info.save(storage, &collection_info)?;
-            // Ok(response
-            //     .add_attribute("migrated collection name", legacy_collection_info.name)
-            //     .add_attribute("migrated collection symbol", legacy_collection_info.symbol))
+            config.legacy_nft_info_cursor.remove(storage);
+            // all NFTs are counted, balances are maintained from now on
+            config.balances_migrated.save(storage, &true)?;
+            response
         }
+    };
+    let response = match counted {
+        0 => response,
+        _ => response.add_attribute("migrated_balances", counted.to_string()),
+    };
+    if migrated == 0 {
+        return Ok(response);
     }
+    Ok(response.add_attribute("migrated_nft_info", migrated.to_string()))
 }
 
 impl<'a>
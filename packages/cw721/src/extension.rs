@@ -144,6 +144,13 @@ impl Default for Cw721EmptyExtensions<'static> {
 ///     minter: None,
 ///     creator: None,
 ///     withdraw_address: None,
+///     enable_snapshots: None,
+///     allow_burned_remint: None,
+///     token_history_depth: None,
+///     token_id_strategy: None,
+///     max_supply: None,
+///     base_uri: None,
+///     reveal: None,
 /// };
 /// //...
 /// // mint:
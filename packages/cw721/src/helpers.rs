@@ -1,12 +1,16 @@
 use std::marker::PhantomData;
 
+use crate::msg::{ClaimLeaf, ClaimMetadata, MintVoucher, Permit, PermitAction, PermitSignDoc};
 use crate::traits::{Cw721Calls, Cw721CustomMsg, Cw721State};
 use crate::{
     DefaultOptionalCollectionExtension, DefaultOptionalCollectionExtensionMsg,
     DefaultOptionalNftExtension, DefaultOptionalNftExtensionMsg,
 };
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Empty};
+use cosmwasm_std::{to_json_vec, Addr, CanonicalAddr, Empty, HexBinary, StdResult};
+use ripemd::Ripemd160;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
 
 /// Returns "empty" if the string is empty, otherwise the string itself
 pub fn value_or_empty(value: &str) -> String {
@@ -17,6 +21,83 @@ pub fn value_or_empty(value: &str) -> String {
     }
 }
 
+/// Returns the reveal commitment, the sha256 hash of the base uri length (8 bytes, big endian),
+/// the base uri and the salt. Length prefix ensures a commitment matches a single base uri.
+pub fn reveal_commitment(base_uri: &str, salt: &str) -> HexBinary {
+    let hash = Sha256::new()
+        .chain_update((base_uri.len() as u64).to_be_bytes())
+        .chain_update(base_uri.as_bytes())
+        .chain_update(salt.as_bytes())
+        .finalize();
+    HexBinary::from(hash.as_slice())
+}
+
+/// Returns the hash signed by the NFT owner, the sha256 hash of the JSON serialized `PermitSignDoc`.
+pub fn permit_hash(action: PermitAction, permit: &Permit) -> StdResult<Vec<u8>> {
+    let sign_doc = to_json_vec(&PermitSignDoc {
+        action,
+        permit: permit.clone(),
+    })?;
+    Ok(Sha256::digest(sign_doc).to_vec())
+}
+
+/// Returns the hash signed by the voucher signer, the sha256 hash of the JSON serialized voucher.
+/// Also used for marking the voucher as redeemed.
+pub fn voucher_hash<TNftExtensionMsg>(voucher: &MintVoucher<TNftExtensionMsg>) -> StdResult<Vec<u8>>
+where
+    TNftExtensionMsg: Serialize,
+{
+    Ok(Sha256::digest(to_json_vec(voucher)?).to_vec())
+}
+
+/// Returns the leaf of the claim merkle tree, the sha256 hash of the JSON serialized `ClaimLeaf`.
+/// Metadata hash is the sha256 hash of the JSON serialized `ClaimMetadata`.
+pub fn claim_leaf<TNftExtensionMsg>(
+    address: &str,
+    token_id: &str,
+    token_uri: Option<String>,
+    extension: TNftExtensionMsg,
+) -> StdResult<HexBinary>
+where
+    TNftExtensionMsg: Serialize,
+{
+    let metadata_hash = Sha256::digest(to_json_vec(&ClaimMetadata {
+        token_uri,
+        extension,
+    })?);
+    let leaf = Sha256::digest(to_json_vec(&ClaimLeaf {
+        address: address.to_string(),
+        token_id: token_id.to_string(),
+        metadata_hash: HexBinary::from(metadata_hash.as_slice()),
+    })?);
+    Ok(HexBinary::from(leaf.as_slice()))
+}
+
+/// Returns true if the proof leads from leaf to root. Pairs are sorted before hashing, so proofs
+/// don't need to specify the position of siblings.
+pub fn verify_merkle_proof(root: &[u8], leaf: &[u8], proof: &[HexBinary]) -> bool {
+    let computed = proof.iter().fold(leaf.to_vec(), |hash, sibling| {
+        merkle_parent(&hash, sibling.as_slice())
+    });
+    computed == root
+}
+
+/// Returns the parent node of two merkle tree nodes, the sha256 hash of the sorted pair.
+pub fn merkle_parent(a: &[u8], b: &[u8]) -> Vec<u8> {
+    let (first, second) = if a <= b { (a, b) } else { (b, a) };
+    Sha256::new()
+        .chain_update(first)
+        .chain_update(second)
+        .finalize()
+        .to_vec()
+}
+
+/// Returns the canonical address derived from a secp256k1 public key, ripemd160 of the sha256 hash (Cosmos SDK).
+pub fn pubkey_to_canonical_addr(pubkey: &[u8]) -> CanonicalAddr {
+    let hash = Ripemd160::digest(Sha256::digest(pubkey));
+    CanonicalAddr::from(hash.as_slice())
+}
+
 #[deprecated(
     since = "0.19.0",
     note = "Please use `DefaultCw721Helper`, `EmptyCw721Helper`, or `Cw721Helper` instead"
@@ -0,0 +1,53 @@
+use schemars::JsonSchema;
+
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{to_json_binary, Binary, CosmosMsg, StdResult, WasmMsg};
+
+use crate::state::TokenHistoryAction;
+
+/// Reply id of hooks ignoring errors, see `HookOnError::Ignore`.
+pub const HOOK_REPLY_ID: u64 = 721;
+
+/// Cw721HookMsg is sent to registered hooks on mint, transfer, send and burn.
+/// Should be de/serialized under `Cw721Hook()` variant in a ExecuteMsg
+#[cw_serde]
+pub struct Cw721HookMsg {
+    pub token_id: String,
+    /// Previous owner, `None` on mint.
+    pub from: Option<String>,
+    /// New owner, `None` on burn.
+    pub to: Option<String>,
+    pub action: TokenHistoryAction,
+}
+
+impl Cw721HookMsg {
+    /// serializes the message
+    pub fn into_json_binary(self) -> StdResult<Binary> {
+        let msg = HookExecuteMsg::Cw721Hook(self);
+        to_json_binary(&msg)
+    }
+
+    /// creates a cosmos_msg sending this struct to the named contract
+    pub fn into_cosmos_msg<TAddress: Into<String>, TCustomResponseMsg>(
+        self,
+        contract_addr: TAddress,
+    ) -> StdResult<CosmosMsg<TCustomResponseMsg>>
+    where
+        TCustomResponseMsg: Clone + std::fmt::Debug + PartialEq + JsonSchema,
+    {
+        let msg = self.into_json_binary()?;
+        let execute = WasmMsg::Execute {
+            contract_addr: contract_addr.into(),
+            msg,
+            funds: vec![],
+        };
+        Ok(execute.into())
+    }
+}
+
+/// This is just a helper to properly serialize the above message.
+/// The actual hook should include this variant in the larger ExecuteMsg enum
+#[cw_serde]
+pub enum HookExecuteMsg {
+    Cw721Hook(Cw721HookMsg),
+}
@@ -2,8 +2,8 @@ use std::collections::HashMap;
 
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::{
-    to_json_binary, Addr, Binary, Coin, ContractInfoResponse, Decimal, Deps, Env, MessageInfo,
-    Timestamp,
+    to_json_binary, Addr, Binary, Coin, ContractInfoResponse, Decimal, Deps, Env, HexBinary,
+    MessageInfo, Timestamp, Uint128,
 };
 use cw_ownable::{Action, Ownership};
 use cw_utils::Expiration;
@@ -13,10 +13,12 @@ use url::Url;
 use crate::error::Cw721ContractError;
 use crate::execute::{assert_creator, assert_minter};
 use crate::state::{
-    Attribute, CollectionExtension, CollectionExtensionAttributes, CollectionInfo, NftInfo, Trait,
-    ATTRIBUTE_DESCRIPTION, ATTRIBUTE_EXPLICIT_CONTENT, ATTRIBUTE_EXTERNAL_LINK, ATTRIBUTE_IMAGE,
-    ATTRIBUTE_ROYALTY_INFO, ATTRIBUTE_START_TRADING_TIME, CREATOR,
-    MAX_COLLECTION_DESCRIPTION_LENGTH, MAX_ROYALTY_SHARE_DELTA_PCT, MAX_ROYALTY_SHARE_PCT, MINTER,
+    Attribute, BaseUri, CollectionExtension, CollectionExtensionAttributes, CollectionInfo,
+    HookOnError, MaxSupply, NftInfo, NftProvenance, OperatorFilterMode, PauseScope, PauseStatus,
+    TokenHistoryAction, TokenIdStrategy, Trait, ATTRIBUTE_DESCRIPTION, ATTRIBUTE_EXPLICIT_CONTENT,
+    ATTRIBUTE_EXTERNAL_LINK, ATTRIBUTE_IMAGE, ATTRIBUTE_ROYALTY_INFO, ATTRIBUTE_START_TRADING_TIME,
+    CREATOR, MAX_COLLECTION_DESCRIPTION_LENGTH, MAX_ROYALTY_SHARE_DELTA_PCT, MAX_ROYALTY_SHARE_PCT,
+    MINTER,
 };
 use crate::traits::{Cw721CustomMsg, Cw721State, FromAttributesState, ToAttributesState};
 use crate::NftExtension;
@@ -36,6 +38,7 @@ pub enum Cw721ExecuteMsg<
     UpdateOwnership(Action),
     UpdateMinterOwnership(Action),
     UpdateCreatorOwnership(Action),
+    UpdatePauserOwnership(Action),
 
     /// The creator is the only one eligible to update `CollectionInfo`.
     UpdateCollectionInfo {
@@ -78,7 +81,8 @@ pub enum Cw721ExecuteMsg<
 
     /// Mint a new NFT, can only be called by the contract minter
     Mint {
-        /// Unique ID of the NFT
+        /// Unique ID of the NFT. In case a token id strategy is configured, an empty token id
+        /// is replaced by a generated one.
         token_id: String,
         /// The owner of the newly minter NFT
         owner: String,
@@ -95,6 +99,134 @@ pub enum Cw721ExecuteMsg<
         token_id: String,
     },
 
+    /// Mint multiple NFTs at once, can only be called by the contract minter.
+    /// All-or-nothing: if one token fails, none are minted.
+    BatchMint {
+        tokens: Vec<MintMsg<TNftExtensionMsg>>,
+    },
+    /// Transfer multiple tokens to the same recipient. All-or-nothing.
+    BatchTransferNft {
+        recipient: String,
+        token_ids: Vec<String>,
+    },
+    /// Send multiple tokens to a contract, triggering `ReceiveNft` for each token. All-or-nothing.
+    BatchSendNft {
+        contract: String,
+        token_ids: Vec<String>,
+        msg: Binary,
+    },
+    /// Burn multiple NFTs the sender has access to. All-or-nothing.
+    BatchBurn {
+        token_ids: Vec<String>,
+    },
+    /// Sets a user with usage rights of an NFT until it expires (ERC-4907), while owner keeps custody.
+    /// `None` removes the user. User is cleared on transfer and burn.
+    SetUser {
+        token_id: String,
+        user: Option<String>,
+        expires: Option<Expiration>,
+    },
+    /// Sets operator filter to allowlist or blocklist mode, `None` disables the filter.
+    /// Only creator can update operator filter.
+    SetOperatorFilterMode {
+        mode: Option<OperatorFilterMode>,
+    },
+    /// Adds operators to allowlist or blocklist.
+    AddFilteredOperators {
+        operators: Vec<String>,
+    },
+    /// Removes operators from allowlist or blocklist.
+    RemoveFilteredOperators {
+        operators: Vec<String>,
+    },
+    /// Pauses given scopes. Only pauser or creator can pause.
+    Pause {
+        scopes: Vec<PauseScope>,
+    },
+    /// Unpauses given scopes. Only pauser or creator can unpause.
+    Unpause {
+        scopes: Vec<PauseScope>,
+    },
+    /// Locks an NFT, so it cannot be transferred, sent or burned. Owner keeps custody.
+    /// Only owner, approved spender or operator can lock. Sender becomes the locker.
+    Lock {
+        token_id: String,
+    },
+    /// Unlocks an NFT. Only the locker can unlock.
+    Unlock {
+        token_id: String,
+    },
+    /// Offers an NFT to a recipient, replacing any pending offer. NFT stays with the owner until the recipient accepts.
+    /// Only owner, approved spender or operator can offer. Pending offers are cleared on transfer, send and burn.
+    OfferTransfer {
+        token_id: String,
+        recipient: String,
+        /// Never expires, if not set.
+        expires: Option<Expiration>,
+    },
+    /// Accepts a pending transfer offer. Only the recipient can accept.
+    AcceptTransfer {
+        token_id: String,
+    },
+    /// Cancels a pending transfer offer. Offerer, owner, approved spender or operator can cancel.
+    CancelTransferOffer {
+        token_id: String,
+    },
+    /// Approves the permit spender on behalf of the NFT owner, who signed the permit off-chain.
+    /// Anyone can submit a permit. The public key must derive to the owner's address.
+    PermitApprove {
+        permit: Permit,
+        /// Compressed secp256k1 public key of the owner.
+        pubkey: Binary,
+        /// Signature of `permit_hash` with `PermitAction::Approve`.
+        signature: Binary,
+    },
+    /// Transfers the NFT to the permit spender on behalf of the NFT owner, who signed the permit off-chain.
+    /// Anyone can submit a permit. The public key must derive to the owner's address.
+    PermitTransfer {
+        permit: Permit,
+        /// Compressed secp256k1 public key of the owner.
+        pubkey: Binary,
+        /// Signature of `permit_hash` with `PermitAction::Transfer`.
+        signature: Binary,
+    },
+    /// Sets the secp256k1 public key signing mint vouchers, or removes it in case `None` is passed.
+    /// Only minter can set the voucher signer.
+    SetVoucherSigner {
+        pubkey: Option<Binary>,
+    },
+    /// Lazy minting: mints the NFT of a voucher signed off-chain by the voucher signer to the sender.
+    /// Voucher price must be sent along. Each voucher can only be redeemed once.
+    RedeemVoucher {
+        voucher: MintVoucher<TNftExtensionMsg>,
+        /// Signature of `voucher_hash`.
+        signature: Binary,
+    },
+    /// Sets the merkle root of the claim allowlist, or removes it in case `None` is passed.
+    /// Only minter can set the merkle root. Already claimed leaves stay claimed.
+    SetMerkleRoot {
+        merkle_root: Option<HexBinary>,
+    },
+    /// Registers a contract, notified with `Cw721HookMsg` on mint, transfer, send and burn.
+    /// Only creator can add hooks. Updates error behaviour of an existing hook.
+    AddHook {
+        contract_addr: String,
+        on_error: HookOnError,
+    },
+    /// Removes a registered hook. Only creator can remove hooks.
+    RemoveHook {
+        contract_addr: String,
+    },
+    /// Mints the NFT of a leaf in the claim merkle tree to the sender. Each leaf can only be claimed once.
+    Claim {
+        /// In case a token id strategy is configured, an empty token id is replaced by a generated one.
+        token_id: String,
+        token_uri: Option<String>,
+        extension: TNftExtensionMsg,
+        /// Sibling hashes from the leaf up to the root, see `claim_leaf` and `merkle_parent`.
+        proof: Vec<HexBinary>,
+    },
+
     /// Custom msg execution. This is a no-op in default implementation.
     UpdateExtension {
         msg: TExtensionMsg,
@@ -108,6 +240,38 @@ pub enum Cw721ExecuteMsg<
         token_uri: Option<String>,
         extension: TNftExtensionMsg,
     },
+    /// Freezes token uri and onchain metadata of an NFT. Only creator can freeze.
+    /// A token uri derived from base uri is pinned, so later base uri changes don't affect it.
+    /// NOTE: freezing is irreversible!
+    FreezeNftInfo {
+        token_id: String,
+    },
+    /// Freezes token uri and onchain metadata of all NFTs, including NFTs minted later on.
+    /// NOTE: freezing is irreversible!
+    FreezeAllNftInfo {},
+    /// Freezes collection info and its extension. NOTE: freezing is irreversible!
+    FreezeCollectionInfo {},
+    /// Sets the base uri, `None` removes it. NFTs without a token uri derive it from
+    /// base uri, token id and optional suffix. Only creator can update.
+    SetBaseUri {
+        base_uri: Option<BaseUri>,
+    },
+    /// Freezes the base uri. NOTE: freezing is irreversible!
+    FreezeBaseUri {},
+    /// Permanently disables minting. Only creator can seal. NOTE: sealing is irreversible!
+    SealSupply {},
+    /// Commits the sha256 hash of the length prefixed final base uri and a salt, see `reveal_commitment`.
+    /// Until revealed, NFTs without own token uri return the placeholder uri. Only allowed before minting.
+    CommitReveal {
+        commitment: HexBinary,
+        placeholder_uri: String,
+    },
+    /// Reveals the final base uri. Base uri and salt must match the committed hash.
+    /// Once revealed, token uri is derived from base uri and token id.
+    Reveal {
+        base_uri: String,
+        salt: String,
+    },
 
     /// Sets address to send withdrawn fees to. Only owner can call this.
     SetWithdrawAddress {
@@ -122,6 +286,22 @@ pub enum Cw721ExecuteMsg<
     },
 }
 
+/// Single token in `Cw721ExecuteMsg::BatchMint`, same as `Cw721ExecuteMsg::Mint`.
+#[cw_serde]
+pub struct MintMsg<TNftExtensionMsg> {
+    /// Unique ID of the NFT. In case a token id strategy is configured, an empty token id
+    /// is replaced by a generated one.
+    pub token_id: String,
+    /// The owner of the newly minted NFT
+    pub owner: String,
+    /// Universal resource identifier for this NFT
+    /// Should point to a JSON file that conforms to the ERC721
+    /// Metadata JSON Schema
+    pub token_uri: Option<String>,
+    /// Any custom extension used by this contract
+    pub extension: TNftExtensionMsg,
+}
+
 #[cw_serde]
 pub struct Cw721InstantiateMsg<TCollectionExtensionMsg> {
     /// Name of the NFT contract
@@ -140,6 +320,98 @@ pub struct Cw721InstantiateMsg<TCollectionExtensionMsg> {
     pub creator: Option<String>,
 
     pub withdraw_address: Option<String>,
+
+    /// Enables ownership snapshots, required for `OwnerOfAtHeight`, `BalanceAtHeight` and
+    /// `TotalSupplyAtHeight` queries. Disabled by default, since it increases gas costs.
+    pub enable_snapshots: Option<bool>,
+
+    /// Burned token ids are recorded and by default can't be minted again.
+    /// If true, burned token ids can be minted again.
+    pub allow_burned_remint: Option<bool>,
+
+    /// Max number of ownership changes (mint, transfer, send, burn) kept per NFT for the
+    /// `TokenHistory` query. Not set or zero disables token history, since it increases gas costs.
+    pub token_history_depth: Option<u32>,
+
+    /// Optional strategy for generating token ids. If set, token ids are generated on mint
+    /// in case of an empty token id.
+    pub token_id_strategy: Option<TokenIdStrategy>,
+
+    /// Optional max supply, enforced on minting.
+    pub max_supply: Option<MaxSupply>,
+
+    /// Optional base uri, NFTs without a token uri derive it from base uri, token id and suffix.
+    pub base_uri: Option<BaseUri>,
+
+    /// Optional delayed reveal, NFTs return the placeholder uri until revealed.
+    pub reveal: Option<RevealCommitmentMsg>,
+}
+
+#[cw_serde]
+pub struct RevealCommitmentMsg {
+    /// sha256 hash of the length prefixed final base uri and a salt, see `reveal_commitment`.
+    pub commitment: HexBinary,
+    pub placeholder_uri: String,
+}
+
+/// Permit signed off-chain by the NFT owner (EIP-4494 style), e.g. for owners without gas tokens.
+#[cw_serde]
+pub struct Permit {
+    pub token_id: String,
+    /// Spender to be approved, or recipient of the NFT.
+    pub spender: String,
+    /// Permit can't be used once expired. On approval, it is also used as approval expiration.
+    pub expires: Expiration,
+    /// Must match the owner's current nonce, see `PermitNonce` query.
+    pub nonce: u64,
+    pub chain_id: String,
+    pub contract_address: String,
+}
+
+#[cw_serde]
+pub enum PermitAction {
+    Approve,
+    Transfer,
+}
+
+/// Document signed by the NFT owner, so a permit for approval can't be used for transfer and vice versa.
+#[cw_serde]
+pub struct PermitSignDoc {
+    pub action: PermitAction,
+    pub permit: Permit,
+}
+
+/// Mint voucher signed off-chain by the voucher signer, so NFTs can be listed without minting upfront.
+#[cw_serde]
+pub struct MintVoucher<TNftExtensionMsg> {
+    /// Unique ID of the NFT. In case a token id strategy is configured, an empty token id
+    /// is replaced by a generated one.
+    pub token_id: String,
+    pub token_uri: Option<String>,
+    pub extension: TNftExtensionMsg,
+    /// Price to be sent along on redemption, free if not set.
+    pub price: Option<Coin>,
+    /// Addresses allowed to redeem the voucher, anyone can redeem if not set.
+    pub recipients: Option<Vec<String>>,
+    pub expires: Expiration,
+    pub chain_id: String,
+    pub contract_address: String,
+}
+
+/// Leaf of the claim merkle tree, see `claim_leaf`.
+#[cw_serde]
+pub struct ClaimLeaf {
+    pub address: String,
+    pub token_id: String,
+    /// sha256 hash of the JSON serialized `ClaimMetadata`.
+    pub metadata_hash: HexBinary,
+}
+
+/// Metadata of a claimable NFT, hashed into `ClaimLeaf`.
+#[cw_serde]
+pub struct ClaimMetadata<TNftExtensionMsg> {
+    pub token_uri: Option<String>,
+    pub extension: TNftExtensionMsg,
 }
 
 #[cw_serde]
@@ -159,6 +431,9 @@ pub enum Cw721QueryMsg<
         /// unset or false will filter out expired approvals, you must set to true to see them
         include_expired: Option<bool>,
     },
+    /// Return the user of the given token (ERC-4907), `None` if not set or expired
+    #[returns(UserOfResponse)]
+    UserOf { token_id: String },
     /// Return operator that can access all of the owner's tokens.
     #[returns(ApprovalResponse)]
     Approval {
@@ -191,6 +466,21 @@ pub enum Cw721QueryMsg<
     /// Total number of tokens issued
     #[returns(NumTokensResponse)]
     NumTokens {},
+    /// Number of tokens owned by the given address
+    #[returns(BalanceOfResponse)]
+    BalanceOf { owner: String },
+    /// Owner of the given token at the beginning of the given block height.
+    /// Requires snapshots to be enabled on instantiation.
+    #[returns(OwnerOfAtHeightResponse)]
+    OwnerOfAtHeight { token_id: String, height: u64 },
+    /// Number of tokens owned by the given address at the beginning of the given block height.
+    /// Requires snapshots to be enabled on instantiation.
+    #[returns(BalanceOfResponse)]
+    BalanceAtHeight { owner: String, height: u64 },
+    /// Total number of tokens at the beginning of the given block height.
+    /// Requires snapshots to be enabled on instantiation.
+    #[returns(NumTokensResponse)]
+    TotalSupplyAtHeight { height: u64 },
 
     #[deprecated(
         since = "0.19.0",
@@ -233,6 +523,36 @@ pub enum Cw721QueryMsg<
     #[returns(Ownership<Addr>)]
     GetCreatorOwnership {},
 
+    #[returns(Ownership<Addr>)]
+    GetPauserOwnership {},
+
+    /// Return operator filter mode and listed operators
+    #[returns(OperatorFilterResponse)]
+    OperatorFilter {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Return whether the given operator (spender, operator or recipient) passes the operator filter
+    #[returns(IsOperatorAllowedResponse)]
+    IsOperatorAllowed { operator: String },
+
+    /// Return whether collection info and NFT infos are frozen. In case a token id is given,
+    /// `nft_info_frozen` is also returned for this NFT.
+    #[returns(FreezeStatusResponse)]
+    FreezeStatus { token_id: Option<String> },
+
+    /// Return the base uri, `None` if not set
+    #[returns(Option<BaseUri>)]
+    BaseUri {},
+
+    /// Return the delayed reveal state
+    #[returns(RevealStatusResponse)]
+    RevealStatus {},
+
+    /// Return which scopes (minting, transfers, approvals, metadata) are paused
+    #[returns(PauseStatus)]
+    PauseStatus {},
+
     /// With MetaData Extension.
     /// Returns metadata about one particular token, based on *ERC721 Metadata JSON Schema*
     /// but directly from the contract
@@ -271,6 +591,67 @@ pub enum Cw721QueryMsg<
         start_after: Option<String>,
         limit: Option<u32>,
     },
+    /// Return whether the given token is locked, and by which locker
+    #[returns(LockStatusResponse)]
+    LockStatus { token_id: String },
+    /// Requires pagination. Lists all token_ids locked by the given locker.
+    #[returns(TokensResponse)]
+    LocksByLocker {
+        locker: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Return pending transfer offer of the given token, if any
+    #[returns(TransferOfferResponse)]
+    TransferOffer { token_id: String },
+    /// Requires pagination. Lists pending transfer offers to the given recipient, including expired ones.
+    #[returns(TransferOffersResponse)]
+    TransferOffersByRecipient {
+        recipient: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Return the nonce expected in the next permit signed by the given owner
+    #[returns(PermitNonceResponse)]
+    PermitNonce { owner: String },
+    /// Return the public key signing mint vouchers, if any
+    #[returns(VoucherSignerResponse)]
+    VoucherSigner {},
+    /// Return whether the voucher with the given hash has been redeemed, see `voucher_hash`
+    #[returns(IsVoucherRedeemedResponse)]
+    IsVoucherRedeemed { voucher_hash: HexBinary },
+    /// Requires pagination. Lists registered hooks.
+    #[returns(HooksResponse)]
+    Hooks {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Return the merkle root of the claim allowlist, if any
+    #[returns(MerkleRootResponse)]
+    MerkleRoot {},
+    /// Return whether the given leaf of the claim merkle tree has been claimed, see `claim_leaf`
+    #[returns(IsClaimedResponse)]
+    IsClaimed { leaf: HexBinary },
+
+    /// Requires pagination. Lists ownership changes of the given token id, oldest first.
+    /// Only the most recent entries are kept, depending on configured token history depth.
+    #[returns(TokenHistoryResponse)]
+    TokenHistory {
+        token_id: String,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+
+    /// Requires pagination. Lists all burned token ids, including who burned them and when.
+    #[returns(BurnedTokensResponse)]
+    BurnedTokens {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+
+    /// Return whether the given token id has been burned.
+    #[returns(IsBurnedResponse)]
+    IsBurned { token_id: String },
 
     /// Custom msg query. Default implementation returns an empty binary.
     #[returns(())]
@@ -281,6 +662,19 @@ pub enum Cw721QueryMsg<
 
     #[returns(Option<String>)]
     GetWithdrawAddress {},
+
+    /// Should be called on sale to see if royalties are owed by the marketplace selling the NFT,
+    /// based on the collection's `RoyaltyInfo`. See https://eips.ethereum.org/EIPS/eip-2981
+    #[returns(RoyaltiesInfoResponse)]
+    RoyaltyInfo {
+        token_id: String,
+        // the denom of this sale must also be the denom returned by RoyaltiesInfoResponse
+        sale_price: Uint128,
+    },
+    /// Called against contract to determine if royalties are implemented, i.e. collection has
+    /// a `RoyaltyInfo` set.
+    #[returns(CheckRoyaltiesResponse)]
+    CheckRoyalties {},
 }
 
 #[cw_serde]
@@ -610,6 +1004,9 @@ pub struct CollectionInfoAndExtensionResponse<TCollectionExtension> {
     pub symbol: String,
     pub extension: TCollectionExtension,
     pub updated_at: Timestamp,
+    pub max_supply: Option<MaxSupply>,
+    /// Once sealed, no more NFTs can be minted
+    pub supply_sealed: bool,
 }
 
 /// This is a wrapper around CollectionInfo that includes the extension, contract info, and number of tokens (supply).
@@ -622,6 +1019,10 @@ pub struct AllInfoResponse {
     pub collection_extension: CollectionExtensionAttributes,
     // NFT details
     pub num_tokens: u64,
+    /// Total number of minted NFTs, including burned ones
+    pub total_minted: u64,
+    pub max_supply: Option<MaxSupply>,
+    pub supply_sealed: bool,
 }
 
 impl<T> From<CollectionInfoAndExtensionResponse<T>> for CollectionInfo {
@@ -674,6 +1075,8 @@ where
                     symbol: self.symbol.clone().unwrap(),
                     extension,
                     updated_at: env.block.time,
+                    max_supply: None,
+                    supply_sealed: false,
                 };
                 Ok(new)
             }
@@ -807,6 +1210,151 @@ pub struct OwnerOfResponse {
     pub approvals: Vec<Approval>,
 }
 
+#[cw_serde]
+pub struct RevealStatusResponse {
+    /// `None` in case no reveal is committed
+    pub commitment: Option<HexBinary>,
+    pub placeholder_uri: Option<String>,
+    pub revealed: bool,
+    /// Final base uri, set once revealed
+    pub base_uri: Option<String>,
+}
+
+#[cw_serde]
+pub struct FreezeStatusResponse {
+    pub collection_info_frozen: bool,
+    pub all_nft_info_frozen: bool,
+    pub base_uri_frozen: bool,
+    /// Only set in case a token id is given
+    pub nft_info_frozen: Option<bool>,
+}
+
+#[cw_serde]
+pub struct OperatorFilterResponse {
+    /// `None` if operator filter is disabled
+    pub mode: Option<OperatorFilterMode>,
+    pub operators: Vec<String>,
+}
+
+#[cw_serde]
+pub struct IsOperatorAllowedResponse {
+    pub allowed: bool,
+}
+
+/// Response data of `Mint`, so calling contracts can read the (generated) token id in replies.
+#[cw_serde]
+pub struct MintResponse {
+    pub token_id: String,
+}
+
+/// Response data of `BatchMint`, so calling contracts can read the (generated) token ids in replies.
+#[cw_serde]
+pub struct BatchMintResponse {
+    pub token_ids: Vec<String>,
+}
+
+#[cw_serde]
+pub struct TokenHistoryItem {
+    /// Sequence of this entry, used for pagination
+    pub seq: u64,
+    pub action: TokenHistoryAction,
+    pub from: Option<String>,
+    pub to: Option<String>,
+    pub height: u64,
+    pub time: Timestamp,
+}
+
+#[cw_serde]
+pub struct TokenHistoryResponse {
+    pub history: Vec<TokenHistoryItem>,
+}
+
+#[cw_serde]
+pub struct BurnedToken {
+    pub token_id: String,
+    pub burned_by: String,
+    pub burned_at: Timestamp,
+}
+
+#[cw_serde]
+pub struct BurnedTokensResponse {
+    pub tokens: Vec<BurnedToken>,
+}
+
+#[cw_serde]
+pub struct IsBurnedResponse {
+    pub burned: bool,
+    pub burned_by: Option<String>,
+    pub burned_at: Option<Timestamp>,
+}
+
+#[cw_serde]
+pub struct LockStatusResponse {
+    pub locked: bool,
+    pub locker: Option<String>,
+}
+
+#[cw_serde]
+pub struct TransferOfferInfo {
+    pub token_id: String,
+    pub offered_by: String,
+    pub recipient: String,
+    pub expires: Expiration,
+}
+
+#[cw_serde]
+pub struct TransferOfferResponse {
+    pub offer: Option<TransferOfferInfo>,
+}
+
+#[cw_serde]
+pub struct TransferOffersResponse {
+    pub offers: Vec<TransferOfferInfo>,
+}
+
+#[cw_serde]
+pub struct PermitNonceResponse {
+    pub nonce: u64,
+}
+
+#[cw_serde]
+pub struct VoucherSignerResponse {
+    pub pubkey: Option<Binary>,
+}
+
+#[cw_serde]
+pub struct IsVoucherRedeemedResponse {
+    pub redeemed: bool,
+}
+
+#[cw_serde]
+pub struct Hook {
+    pub contract_addr: String,
+    pub on_error: HookOnError,
+}
+
+#[cw_serde]
+pub struct HooksResponse {
+    pub hooks: Vec<Hook>,
+}
+
+#[cw_serde]
+pub struct MerkleRootResponse {
+    pub merkle_root: Option<HexBinary>,
+}
+
+#[cw_serde]
+pub struct IsClaimedResponse {
+    pub claimed: bool,
+}
+
+#[cw_serde]
+pub struct UserOfResponse {
+    /// User of the token, `None` if not set or expired
+    pub user: Option<String>,
+    pub expires: Option<Expiration>,
+}
+
 #[cw_serde]
 pub struct ApprovalResponse {
     pub approval: Approval,
@@ -832,6 +1380,32 @@ pub struct NumTokensResponse {
     pub count: u64,
 }
 
+#[cw_serde]
+pub struct BalanceOfResponse {
+    pub balance: u64,
+}
+
+#[cw_serde]
+pub struct OwnerOfAtHeightResponse {
+    /// `None` in case token did not exist at given height.
+    pub owner: Option<String>,
+}
+
+#[cw_serde]
+pub struct RoyaltiesInfoResponse {
+    pub address: String,
+    // Note that this must be the same denom as that passed in to RoyaltyInfo
+    // rounding up or down is at the discretion of the implementer
+    pub royalty_amount: Uint128,
+}
+
+/// Shows if the contract implements royalties
+/// if royalty_payments is true, marketplaces should pay them
+#[cw_serde]
+pub struct CheckRoyaltiesResponse {
+    pub royalty_payments: bool,
+}
+
 #[cw_serde]
 pub struct NftInfoResponse<TNftExtension> {
     /// Universal resource identifier for this NFT
@@ -848,6 +1422,8 @@ pub struct AllNftInfoResponse<TNftExtension> {
     pub access: OwnerOfResponse,
     /// Data on the token itself,
     pub info: NftInfoResponse<TNftExtension>,
+    /// Who minted the token and when, and when it was last transferred
+    pub provenance: NftProvenance,
 }
 
 #[cw_serde]
@@ -869,8 +1445,6 @@ pub struct MinterResponse {
 pub struct NftInfoMsg<TNftExtensionMsg> {
     /// The owner of the newly minted NFT
     pub owner: String,
-    /// Approvals are stored here, as we clear them all upon transfer and cannot accumulate much
-    pub approvals: Vec<Approval>,
 
     /// Universal resource identifier for this NFT
     /// Should point to a JSON file that conforms to the ERC721
@@ -897,7 +1471,7 @@ where
     ) -> Result<NftInfo<TNftExtension>, Cw721ContractError> {
         self.validate(deps, env, info, optional_current)?;
         match optional_current {
-            // Some: update only token uri and extension in existing NFT (but not owner and approvals)
+            // Some: update only token uri and extension in existing NFT (but not owner)
             Some(current) => {
                 let mut updated = current.clone();
                 if self.token_uri.is_some() {
@@ -915,9 +1489,9 @@ where
                 let token_uri = empty_as_none(self.token_uri.clone());
                 Ok(NftInfo {
                     owner: Addr::unchecked(&self.owner), // only for creation we use owner, but not for update!
-                    approvals: vec![],
                     token_uri,
                     extension,
+                    provenance: NftProvenance::default(),
                 })
             }
         }
@@ -1,5 +1,6 @@
 use cosmwasm_std::{
-    Addr, BlockInfo, CustomMsg, Deps, Empty, Env, Order, StdError, StdResult, Storage,
+    from_json, Addr, BlockInfo, CustomMsg, Deps, Empty, Env, HexBinary, Order, StdError, StdResult,
+    Storage, Uint128,
 };
 use cw_ownable::Ownership;
 use cw_storage_plus::Bound;
@@ -12,12 +13,19 @@ use crate::{
     },
     msg::{
         AllInfoResponse, AllNftInfoResponse, ApprovalResponse, ApprovalsResponse,
-        CollectionInfoAndExtensionResponse, ConfigResponse, MinterResponse, NftInfoResponse,
-        NumTokensResponse, OperatorResponse, OperatorsResponse, OwnerOfResponse, TokensResponse,
+        BalanceOfResponse, BurnedToken, BurnedTokensResponse, CheckRoyaltiesResponse,
+        CollectionInfoAndExtensionResponse, ConfigResponse, FreezeStatusResponse, Hook,
+        HooksResponse, IsBurnedResponse, IsClaimedResponse, IsOperatorAllowedResponse,
+        IsVoucherRedeemedResponse, LockStatusResponse, MerkleRootResponse, MinterResponse,
+        NftInfoResponse, NumTokensResponse, OperatorFilterResponse, OperatorResponse,
+        OperatorsResponse, OwnerOfAtHeightResponse, OwnerOfResponse, PermitNonceResponse,
+        RevealStatusResponse, RoyaltiesInfoResponse, TokenHistoryItem, TokenHistoryResponse,
+        TokensResponse, TransferOfferInfo, TransferOfferResponse, TransferOffersResponse,
+        UserOfResponse, VoucherSignerResponse,
     },
     state::{
-        Approval, CollectionExtensionAttributes, CollectionInfo, Cw721Config, NftInfo, CREATOR,
-        MINTER,
+        Approval, BaseUri, CollectionExtensionAttributes, CollectionInfo, Cw721Config, NftMetadata,
+        PauseStatus, RoyaltyInfo, TransferOffer, ATTRIBUTE_ROYALTY_INFO, CREATOR, MINTER, PAUSER,
     },
     traits::{Contains, Cw721CustomMsg, Cw721Query, Cw721State, FromAttributesState},
     DefaultOptionalCollectionExtension, DefaultOptionalNftExtension,
@@ -31,20 +39,19 @@ pub fn parse_approval(item: StdResult<(Addr, Expiration)>) -> StdResult<Approval
     item.map(|(spender, expires)| Approval { spender, expires })
 }
 
-pub fn humanize_approvals<TNftExtension>(
+pub fn humanize_approvals(
+    storage: &dyn Storage,
     block: &BlockInfo,
-    nft_info: &NftInfo<TNftExtension>,
+    token_id: &str,
     include_expired_approval: bool,
-) -> Vec<Approval>
-where
-    TNftExtension: Cw721State,
-{
-    nft_info
-        .approvals
+) -> StdResult<Vec<Approval>> {
+    let approvals = Cw721Config::<Option<Empty>>::default()
+        .load_token_approvals(storage, token_id)?
         .iter()
         .filter(|apr| include_expired_approval || !apr.is_expired(block))
         .map(humanize_approval)
-        .collect()
+        .collect();
+    Ok(approvals)
 }
 
 pub fn humanize_approval(approval: &Approval) -> Approval {
@@ -74,6 +81,62 @@ pub fn query_creator_ownership(storage: &dyn Storage) -> StdResult<Ownership<Add
     CREATOR.get_ownership(storage)
 }
 
+pub fn query_pauser_ownership(storage: &dyn Storage) -> StdResult<Ownership<Addr>> {
+    PAUSER.get_ownership(storage)
+}
+
+pub fn query_base_uri(storage: &dyn Storage) -> StdResult<Option<BaseUri>> {
+    Cw721Config::<Option<Empty>>::default()
+        .base_uri
+        .may_load(storage)
+}
+
+pub fn query_reveal_status(deps: Deps) -> StdResult<RevealStatusResponse> {
+    let reveal = Cw721Config::<Option<Empty>>::default()
+        .reveal
+        .may_load(deps.storage)?;
+    Ok(match reveal {
+        Some(reveal) => RevealStatusResponse {
+            commitment: Some(reveal.commitment),
+            placeholder_uri: Some(reveal.placeholder_uri),
+            revealed: reveal.base_uri.is_some(),
+            base_uri: reveal.base_uri,
+        },
+        None => RevealStatusResponse {
+            commitment: None,
+            placeholder_uri: None,
+            revealed: false,
+            base_uri: None,
+        },
+    })
+}
+
+pub fn query_freeze_status(
+    deps: Deps,
+    token_id: Option<String>,
+) -> StdResult<FreezeStatusResponse> {
+    let config = Cw721Config::<Option<Empty>>::default();
+    let metadata_freeze = config.load_metadata_freeze(deps.storage)?;
+    let nft_info_frozen = match token_id {
+        Some(token_id) => {
+            // ensure token exists
+            config.nft_owner_info.load(deps.storage, &token_id)?;
+            Some(config.is_nft_info_frozen(deps.storage, &token_id)?)
+        }
+        None => None,
+    };
+    Ok(FreezeStatusResponse {
+        collection_info_frozen: metadata_freeze.collection_info,
+        all_nft_info_frozen: metadata_freeze.all_nft_info,
+        base_uri_frozen: metadata_freeze.base_uri,
+        nft_info_frozen,
+    })
+}
+
+pub fn query_pause_status(storage: &dyn Storage) -> StdResult<PauseStatus> {
+    Cw721Config::<Option<Empty>>::default().load_pause_status(storage)
+}
+
 pub fn query_collection_info(storage: &dyn Storage) -> StdResult<CollectionInfo> {
     let config = Cw721Config::<Option<Empty>>::default();
     config.collection_info.load(storage)
@@ -124,18 +187,22 @@ where
     let collection_info = query_collection_info(deps.storage)?;
     let attributes = query_collection_extension_attributes(deps)?;
     let extension = FromAttributesState::from_attributes_state(&attributes)?;
+    let config = Cw721Config::<Option<Empty>>::default();
     Ok(CollectionInfoAndExtensionResponse {
         name: collection_info.name,
         symbol: collection_info.symbol,
         updated_at: collection_info.updated_at,
         extension,
+        max_supply: config.max_supply.may_load(deps.storage)?,
+        supply_sealed: config.is_supply_sealed(deps.storage)?,
     })
 }
 
 pub fn query_all_info(deps: Deps, env: &Env) -> StdResult<AllInfoResponse> {
     let collection_info = query_collection_info(deps.storage)?;
     let attributes = query_collection_extension_attributes(deps)?;
-    let num_tokens = Cw721Config::<Option<Empty>>::default().token_count(deps.storage)?;
+    let config = Cw721Config::<Option<Empty>>::default();
+    let num_tokens = config.token_count(deps.storage)?;
     let contract_info = deps
         .querier
         .query_wasm_contract_info(env.contract.address.clone())?;
@@ -143,6 +210,9 @@ pub fn query_all_info(deps: Deps, env: &Env) -> StdResult<AllInfoResponse> {
         collection_info,
         collection_extension: attributes,
         num_tokens,
+        total_minted: config.minted_count(deps.storage)?,
+        max_supply: config.max_supply.may_load(deps.storage)?,
+        supply_sealed: config.is_supply_sealed(deps.storage)?,
         contract_info,
     })
 }
@@ -152,6 +222,56 @@ pub fn query_num_tokens(storage: &dyn Storage) -> StdResult<NumTokensResponse> {
     Ok(NumTokensResponse { count })
 }
 
+pub fn query_balance_of(deps: Deps, owner: String) -> StdResult<BalanceOfResponse> {
+    let owner = deps.api.addr_validate(&owner)?;
+    let balance = Cw721Config::<Option<Empty>>::default().balance_of(deps.storage, &owner)?;
+    Ok(BalanceOfResponse { balance })
+}
+
+fn assert_snapshots_enabled(storage: &dyn Storage) -> StdResult<()> {
+    if !Cw721Config::<Option<Empty>>::default().has_snapshots(storage)? {
+        return Err(StdError::generic_err("Snapshots are not enabled"));
+    }
+    Ok(())
+}
+
+pub fn query_owner_of_at_height(
+    deps: Deps,
+    token_id: String,
+    height: u64,
+) -> StdResult<OwnerOfAtHeightResponse> {
+    assert_snapshots_enabled(deps.storage)?;
+    let owner = Cw721Config::<Option<Empty>>::default()
+        .snapshot_owners
+        .may_load_at_height(deps.storage, &token_id, height)?;
+    Ok(OwnerOfAtHeightResponse {
+        owner: owner.map(|owner| owner.to_string()),
+    })
+}
+
+pub fn query_balance_at_height(
+    deps: Deps,
+    owner: String,
+    height: u64,
+) -> StdResult<BalanceOfResponse> {
+    assert_snapshots_enabled(deps.storage)?;
+    let owner = deps.api.addr_validate(&owner)?;
+    let balance = Cw721Config::<Option<Empty>>::default()
+        .snapshot_balances
+        .may_load_at_height(deps.storage, &owner, height)?
+        .unwrap_or_default();
+    Ok(BalanceOfResponse { balance })
+}
+
+pub fn query_total_supply_at_height(deps: Deps, height: u64) -> StdResult<NumTokensResponse> {
+    assert_snapshots_enabled(deps.storage)?;
+    let count = Cw721Config::<Option<Empty>>::default()
+        .snapshot_num_tokens
+        .may_load_at_height(deps.storage, height)?
+        .unwrap_or_default();
+    Ok(NumTokensResponse { count })
+}
+
 pub fn query_nft_info<TNftExtension>(
     storage: &dyn Storage,
     token_id: String,
@@ -159,11 +279,10 @@ pub fn query_nft_info<TNftExtension>(
 where
     TNftExtension: Cw721State,
 {
-    let info = Cw721Config::<TNftExtension>::default()
-        .nft_info
-        .load(storage, &token_id)?;
+    let config = Cw721Config::<TNftExtension>::default();
+    let info = config.nft_metadata.load(storage, &token_id)?;
     Ok(NftInfoResponse {
-        token_uri: info.token_uri,
+        token_uri: config.resolve_token_uri(storage, &token_id, info.token_uri)?,
         extension: info.extension,
     })
 }
@@ -180,14 +299,15 @@ where
     let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
     let start = start_after.map(|s| Bound::ExclusiveRaw(s.into()));
 
-    let nfts: Vec<Option<NftInfo<TNftExtension>>> = Cw721Config::<TNftExtension>::default()
-        .nft_info
+    let config = Cw721Config::<TNftExtension>::default();
+    let nfts: Vec<Option<(String, NftMetadata<TNftExtension>)>> = config
+        .nft_metadata
         .range(storage, start, None, Order::Ascending)
         .take(limit)
         .map(|kv| {
-            let nft = kv?.1;
+            let (token_id, nft) = kv?;
             let result = if nft.extension.contains(&extension) {
-                Some(nft)
+                Some((token_id, nft))
             } else {
                 None
             };
@@ -195,13 +315,15 @@ where
         })
         .collect::<StdResult<_>>()?;
     let filtered = nfts
-        .iter()
-        .filter_map(|n| n.clone())
-        .map(|n| NftInfoResponse {
-            token_uri: n.token_uri,
-            extension: n.extension,
+        .into_iter()
+        .flatten()
+        .map(|(token_id, n)| {
+            Ok(NftInfoResponse {
+                token_uri: config.resolve_token_uri(storage, &token_id, n.token_uri)?,
+                extension: n.extension,
+            })
         })
-        .collect::<Vec<NftInfoResponse<TNftExtension>>>();
+        .collect::<StdResult<Vec<NftInfoResponse<TNftExtension>>>>()?;
     if filtered.is_empty() {
         Ok(None)
     } else {
@@ -216,14 +338,35 @@ pub fn query_owner_of(
     include_expired_approval: bool,
 ) -> StdResult<OwnerOfResponse> {
     let nft_info = Cw721Config::<Option<Empty>>::default()
-        .nft_info
+        .nft_owner_info
         .load(deps.storage, &token_id)?;
     Ok(OwnerOfResponse {
         owner: nft_info.owner.to_string(),
-        approvals: humanize_approvals(&env.block, &nft_info, include_expired_approval),
+        approvals: humanize_approvals(
+            deps.storage,
+            &env.block,
+            &token_id,
+            include_expired_approval,
+        )?,
     })
 }
 
+pub fn query_user_of(deps: Deps, env: &Env, token_id: String) -> StdResult<UserOfResponse> {
+    let nft_info = Cw721Config::<Option<Empty>>::default()
+        .nft_owner_info
+        .load(deps.storage, &token_id)?;
+    match nft_info.user {
+        Some(user) if !user.is_expired(&env.block) => Ok(UserOfResponse {
+            user: Some(user.user.to_string()),
+            expires: Some(user.expires),
+        }),
+        _ => Ok(UserOfResponse {
+            user: None,
+            expires: None,
+        }),
+    }
+}
+
 /// operator returns the approval status of an operator for a given owner if exists
 pub fn query_operator(
     deps: Deps,
@@ -282,6 +425,36 @@ pub fn query_operators(
     Ok(OperatorsResponse { operators: res? })
 }
 
+pub fn query_operator_filter(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<OperatorFilterResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start_addr = maybe_addr(deps.api, start_after)?;
+    let start = start_addr.as_ref().map(Bound::exclusive);
+
+    let config = Cw721Config::<Option<Empty>>::default();
+    let mode = config.operator_filter_mode.may_load(deps.storage)?;
+    let operators = config
+        .operator_filter
+        .keys(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|operator| operator.map(|operator| operator.to_string()))
+        .collect::<StdResult<Vec<_>>>()?;
+    Ok(OperatorFilterResponse { mode, operators })
+}
+
+pub fn query_is_operator_allowed(
+    deps: Deps,
+    operator: String,
+) -> StdResult<IsOperatorAllowedResponse> {
+    let operator = deps.api.addr_validate(&operator)?;
+    let allowed =
+        Cw721Config::<Option<Empty>>::default().is_operator_allowed(deps.storage, &operator)?;
+    Ok(IsOperatorAllowedResponse { allowed })
+}
+
 pub fn query_approval(
     deps: Deps,
     env: &Env,
@@ -289,9 +462,8 @@ pub fn query_approval(
     spender: Addr,
     include_expired_approval: bool,
 ) -> StdResult<ApprovalResponse> {
-    let token = Cw721Config::<Option<Empty>>::default()
-        .nft_info
-        .load(deps.storage, &token_id)?;
+    let config = Cw721Config::<Option<Empty>>::default();
+    let token = config.nft_owner_info.load(deps.storage, &token_id)?;
 
     // token owner has absolute approval
     if token.owner == spender {
@@ -302,22 +474,12 @@ pub fn query_approval(
         return Ok(ApprovalResponse { approval });
     }
 
-    let filtered: Vec<_> = token
-        .approvals
-        .into_iter()
-        .filter(|t| t.spender == spender)
-        .filter(|t| include_expired_approval || !t.is_expired(&env.block))
-        .map(|a| Approval {
-            spender: a.spender,
-            expires: a.expires,
-        })
-        .collect();
-
-    if filtered.is_empty() {
-        return Err(StdError::not_found("Approval not found"));
-    }
-    // we expect only one item
-    let approval = filtered[0].clone();
+    let approval = config
+        .token_approvals
+        .may_load(deps.storage, (&token_id, &spender))?
+        .map(|expires| Approval { spender, expires })
+        .filter(|apr| include_expired_approval || !apr.is_expired(&env.block))
+        .ok_or_else(|| StdError::not_found("Approval not found"))?;
 
     Ok(ApprovalResponse { approval })
 }
@@ -329,18 +491,16 @@ pub fn query_approvals(
     token_id: String,
     include_expired_approval: bool,
 ) -> StdResult<ApprovalsResponse> {
-    let token = Cw721Config::<Option<Empty>>::default()
-        .nft_info
+    // ensure token exists
+    Cw721Config::<Option<Empty>>::default()
+        .nft_owner_info
         .load(deps.storage, &token_id)?;
-    let approvals: Vec<_> = token
-        .approvals
-        .into_iter()
-        .filter(|t| include_expired_approval || !t.is_expired(&env.block))
-        .map(|a| Approval {
-            spender: a.spender,
-            expires: a.expires,
-        })
-        .collect();
+    let approvals = humanize_approvals(
+        deps.storage,
+        &env.block,
+        &token_id,
+        include_expired_approval,
+    )?;
 
     Ok(ApprovalsResponse { approvals })
 }
@@ -357,7 +517,7 @@ pub fn query_tokens(
 
     let owner_addr = deps.api.addr_validate(&owner)?;
     let tokens: Vec<String> = Cw721Config::<Option<Empty>>::default()
-        .nft_info
+        .nft_owner_info
         .idx
         .owner
         .prefix(owner_addr)
@@ -368,6 +528,205 @@ pub fn query_tokens(
     Ok(TokensResponse { tokens })
 }
 
+pub fn query_token_history(
+    deps: Deps,
+    token_id: String,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<TokenHistoryResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive);
+
+    let history = Cw721Config::<Option<Empty>>::default()
+        .token_history
+        .prefix(&token_id)
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| {
+            item.map(|(seq, entry)| TokenHistoryItem {
+                seq,
+                action: entry.action,
+                from: entry.from.map(|from| from.to_string()),
+                to: entry.to.map(|to| to.to_string()),
+                height: entry.height,
+                time: entry.time,
+            })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+    Ok(TokenHistoryResponse { history })
+}
+
+pub fn query_burned_tokens(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<BurnedTokensResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(|s| Bound::ExclusiveRaw(s.into()));
+
+    let tokens = Cw721Config::<Option<Empty>>::default()
+        .burned_tokens
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| {
+            item.map(|(token_id, burn_info)| BurnedToken {
+                token_id,
+                burned_by: burn_info.burned_by.to_string(),
+                burned_at: burn_info.burned_at,
+            })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+    Ok(BurnedTokensResponse { tokens })
+}
+
+pub fn query_is_burned(deps: Deps, token_id: String) -> StdResult<IsBurnedResponse> {
+    let burn_info = Cw721Config::<Option<Empty>>::default()
+        .burned_tokens
+        .may_load(deps.storage, &token_id)?;
+    Ok(IsBurnedResponse {
+        burned: burn_info.is_some(),
+        burned_by: burn_info
+            .as_ref()
+            .map(|burn_info| burn_info.burned_by.to_string()),
+        burned_at: burn_info.map(|burn_info| burn_info.burned_at),
+    })
+}
+
+pub fn query_lock_status(deps: Deps, token_id: String) -> StdResult<LockStatusResponse> {
+    let locker = Cw721Config::<Option<Empty>>::default()
+        .token_locks
+        .may_load(deps.storage, &token_id)?;
+    Ok(LockStatusResponse {
+        locked: locker.is_some(),
+        locker: locker.map(|locker| locker.to_string()),
+    })
+}
+
+pub fn query_locks_by_locker(
+    deps: Deps,
+    locker: String,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<TokensResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(|s| Bound::ExclusiveRaw(s.into()));
+
+    let locker_addr = deps.api.addr_validate(&locker)?;
+    let tokens: Vec<String> = Cw721Config::<Option<Empty>>::default()
+        .token_locks
+        .idx
+        .locker
+        .prefix(locker_addr)
+        .keys(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(TokensResponse { tokens })
+}
+
+pub fn query_transfer_offer(deps: Deps, token_id: String) -> StdResult<TransferOfferResponse> {
+    let offer = Cw721Config::<Option<Empty>>::default()
+        .transfer_offers
+        .may_load(deps.storage, &token_id)?
+        .map(|offer| transfer_offer_info(token_id, offer));
+    Ok(TransferOfferResponse { offer })
+}
+
+pub fn query_transfer_offers_by_recipient(
+    deps: Deps,
+    recipient: String,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<TransferOffersResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(|s| Bound::ExclusiveRaw(s.into()));
+
+    let recipient_addr = deps.api.addr_validate(&recipient)?;
+    let offers = Cw721Config::<Option<Empty>>::default()
+        .transfer_offers
+        .idx
+        .recipient
+        .prefix(recipient_addr)
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(token_id, offer)| transfer_offer_info(token_id, offer)))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(TransferOffersResponse { offers })
+}
+
+pub fn query_permit_nonce(deps: Deps, owner: String) -> StdResult<PermitNonceResponse> {
+    let owner_addr = deps.api.addr_validate(&owner)?;
+    let nonce = Cw721Config::<Option<Empty>>::default()
+        .permit_nonces
+        .may_load(deps.storage, &owner_addr)?
+        .unwrap_or_default();
+    Ok(PermitNonceResponse { nonce })
+}
+
+pub fn query_voucher_signer(deps: Deps) -> StdResult<VoucherSignerResponse> {
+    let pubkey = Cw721Config::<Option<Empty>>::default()
+        .voucher_signer
+        .may_load(deps.storage)?;
+    Ok(VoucherSignerResponse { pubkey })
+}
+
+pub fn query_is_voucher_redeemed(
+    deps: Deps,
+    voucher_hash: HexBinary,
+) -> StdResult<IsVoucherRedeemedResponse> {
+    let redeemed = Cw721Config::<Option<Empty>>::default()
+        .redeemed_vouchers
+        .has(deps.storage, voucher_hash.as_slice());
+    Ok(IsVoucherRedeemedResponse { redeemed })
+}
+
+pub fn query_hooks(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<HooksResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start_addr = maybe_addr(deps.api, start_after)?;
+    let start = start_addr.as_ref().map(Bound::exclusive);
+
+    let hooks = Cw721Config::<Option<Empty>>::default()
+        .hooks
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| {
+            item.map(|(contract_addr, on_error)| Hook {
+                contract_addr: contract_addr.to_string(),
+                on_error,
+            })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+    Ok(HooksResponse { hooks })
+}
+
+pub fn query_merkle_root(deps: Deps) -> StdResult<MerkleRootResponse> {
+    let merkle_root = Cw721Config::<Option<Empty>>::default()
+        .merkle_root
+        .may_load(deps.storage)?;
+    Ok(MerkleRootResponse { merkle_root })
+}
+
+pub fn query_is_claimed(deps: Deps, leaf: HexBinary) -> StdResult<IsClaimedResponse> {
+    let claimed = Cw721Config::<Option<Empty>>::default()
+        .claimed_leaves
+        .has(deps.storage, leaf.as_slice());
+    Ok(IsClaimedResponse { claimed })
+}
+
+fn transfer_offer_info(token_id: String, offer: TransferOffer) -> TransferOfferInfo {
+    TransferOfferInfo {
+        token_id,
+        offered_by: offer.offered_by.to_string(),
+        recipient: offer.recipient.to_string(),
+        expires: offer.expires,
+    }
+}
+
 pub fn query_all_tokens(
     deps: Deps,
     _env: &Env,
@@ -378,10 +737,9 @@ pub fn query_all_tokens(
     let start = start_after.map(|s| Bound::ExclusiveRaw(s.into()));
 
     let tokens = Cw721Config::<Option<Empty>>::default()
-        .nft_info
-        .range(deps.storage, start, None, Order::Ascending)
+        .nft_owner_info
+        .keys(deps.storage, start, None, Order::Ascending)
         .take(limit)
-        .map(|item| item.map(|(k, _)| k))
         .collect::<StdResult<Vec<_>>>()?;
 
     Ok(TokensResponse { tokens })
@@ -396,18 +754,23 @@ pub fn query_all_nft_info<TNftExtension>(
 where
     TNftExtension: Cw721State,
 {
-    let nft_info = Cw721Config::<TNftExtension>::default()
-        .nft_info
-        .load(deps.storage, &token_id)?;
+    let config = Cw721Config::<TNftExtension>::default();
+    let nft_info = config.load_nft_info(deps.storage, &token_id)?;
     Ok(AllNftInfoResponse {
         access: OwnerOfResponse {
             owner: nft_info.owner.to_string(),
-            approvals: humanize_approvals(&env.block, &nft_info, include_expired_approval),
+            approvals: humanize_approvals(
+                deps.storage,
+                &env.block,
+                &token_id,
+                include_expired_approval,
+            )?,
         },
         info: NftInfoResponse {
-            token_uri: nft_info.token_uri,
+            token_uri: config.resolve_token_uri(deps.storage, &token_id, nft_info.token_uri)?,
             extension: nft_info.extension,
         },
+        provenance: nft_info.provenance,
     })
 }
 
@@ -417,6 +780,47 @@ pub fn query_withdraw_address(deps: Deps) -> StdResult<Option<String>> {
         .may_load(deps.storage)
 }
 
+fn load_royalty_info(storage: &dyn Storage) -> StdResult<Option<RoyaltyInfo>> {
+    let royalty_info = Cw721Config::<Option<Empty>>::default()
+        .collection_extension
+        .may_load(storage, ATTRIBUTE_ROYALTY_INFO.to_string())?;
+    match royalty_info {
+        Some(attr) => from_json(&attr.value),
+        None => Ok(None),
+    }
+}
+
+/// NOTE: default behaviour here is to round down
+/// EIP2981 specifies that the rounding behaviour is at the discretion of the implementer
+pub fn query_royalty_info(
+    deps: Deps,
+    token_id: String,
+    sale_price: Uint128,
+) -> StdResult<RoyaltiesInfoResponse> {
+    // ensure token exists
+    Cw721Config::<Option<Empty>>::default()
+        .nft_owner_info
+        .load(deps.storage, &token_id)?;
+    let response = match load_royalty_info(deps.storage)? {
+        Some(royalty_info) => RoyaltiesInfoResponse {
+            address: royalty_info.payment_address.to_string(),
+            royalty_amount: sale_price.mul_floor(royalty_info.share),
+        },
+        None => RoyaltiesInfoResponse {
+            address: String::new(),
+            royalty_amount: Uint128::zero(),
+        },
+    };
+    Ok(response)
+}
+
+/// Royalties are set at collection level, so royalties are owed in case collection has a `RoyaltyInfo`.
+pub fn query_check_royalties(deps: Deps) -> StdResult<CheckRoyaltiesResponse> {
+    Ok(CheckRoyaltiesResponse {
+        royalty_payments: load_royalty_info(deps.storage)?.is_some(),
+    })
+}
+
 impl<'a> Cw721Query<DefaultOptionalNftExtension, DefaultOptionalCollectionExtension, Empty>
     for Cw721OnchainExtensions<'a>
 {
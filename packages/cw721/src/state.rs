@@ -1,12 +1,15 @@
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{
-    from_json, to_json_binary, Addr, Binary, BlockInfo, Decimal, Deps, Empty, Env, MessageInfo,
-    StdResult, Storage, Timestamp,
+    from_json, to_json_binary, Addr, Binary, BlockInfo, Decimal, Deps, Empty, Env, HexBinary,
+    MessageInfo, Order, StdResult, Storage, Timestamp,
 };
 use cw_ownable::{OwnershipStore, OWNERSHIP_KEY};
-use cw_storage_plus::{Index, IndexList, IndexedMap, Item, Map, MultiIndex};
+use cw_storage_plus::{
+    Index, IndexList, IndexedMap, Item, Map, MultiIndex, SnapshotItem, SnapshotMap, Strategy,
+};
 use cw_utils::Expiration;
 use serde::de::DeserializeOwned;
+use std::fmt;
 
 use crate::error::Cw721ContractError;
 use crate::traits::{Contains, Cw721CustomMsg, Cw721State, FromAttributesState, ToAttributesState};
@@ -19,6 +22,8 @@ use crate::{traits::StateFactory, NftExtensionMsg};
 pub const CREATOR: OwnershipStore = OwnershipStore::new(OWNERSHIP_KEY);
 /// - minter is stored in the contract storage using cw_ownable::OwnershipStore (same as for OWNERSHIP but with different key)
 pub const MINTER: OwnershipStore = OwnershipStore::new("collection_minter");
+/// - pauser can pause minting, transfers, approvals and metadata updates (besides creator), initialized with creator
+pub const PAUSER: OwnershipStore = OwnershipStore::new("collection_pauser");
 
 // ----------------------
 // NOTE: below are max restrictions for default collection extension (CollectionExtensionResponse)
@@ -55,8 +60,78 @@ pub struct Cw721Config<
     /// Stored as (granter, operator) giving operator full control over granter's account.
     /// NOTE: granter is the owner, so operator has only control for NFTs owned by granter!
     pub operators: Map<(&'a Addr, &'a Addr), Expiration>,
-    pub nft_info: IndexedMap<&'a str, NftInfo<TNftExtension>, TokenIndexes<'a, TNftExtension>>,
+    /// Lightweight ownership record, indexed by owner. Transfers only load and save this record.
+    /// NOTE: uses same key as legacy `nft_info`, so owner index stays valid!
+    pub nft_owner_info: IndexedMap<&'a str, NftOwnerInfo, TokenIndexes<'a>>,
+    /// Token uri and onchain metadata, stored separately from ownership record.
+    pub nft_metadata: Map<&'a str, NftMetadata<TNftExtension>>,
     pub withdraw_address: Item<String>,
+    /// Stored as (token_id, spender) giving spender control over a single NFT.
+    /// NOTE: approvals are stored separately from `NftInfo`, so approve and revoke do not rewrite NFT metadata!
+    pub token_approvals: Map<(&'a str, &'a Addr), Expiration>,
+    /// Number of NFTs owned by an address, updated on mint, burn, transfer and send.
+    pub balances: Map<&'a Addr, u64>,
+    /// Optional ownership snapshots, e.g. for governance voting power. Set on instantiation.
+    pub snapshots_enabled: Item<bool>,
+    /// Snapshots of NFT owners, only maintained in case snapshots are enabled.
+    pub snapshot_owners: SnapshotMap<&'a str, Addr>,
+    /// Snapshots of per-owner balances, only maintained in case snapshots are enabled.
+    pub snapshot_balances: SnapshotMap<&'a Addr, u64>,
+    /// Snapshots of total supply, only maintained in case snapshots are enabled.
+    pub snapshot_num_tokens: SnapshotItem<u64>,
+    /// Locker of a locked NFT, indexed by locker. Locked NFTs cannot be transferred, sent or burned.
+    pub token_locks: IndexedMap<&'a str, Addr, LockIndexes<'a>>,
+    pub pause_status: Item<PauseStatus>,
+    /// Creator-managed operator filter, e.g. for enforcing marketplace royalties. Disabled if not set.
+    pub operator_filter_mode: Item<OperatorFilterMode>,
+    /// Operators in allowlist or blocklist, depending on `operator_filter_mode`.
+    pub operator_filter: Map<&'a Addr, Empty>,
+    /// NFTs with frozen token uri and onchain metadata. NOTE: freezing is irreversible!
+    pub frozen_nft_info: Map<&'a str, Empty>,
+    /// Collection-wide metadata freeze. NOTE: freezing is irreversible!
+    pub metadata_freeze: Item<MetadataFreeze>,
+    /// Delayed reveal: committed hash of final base uri and placeholder uri shown until revealed.
+    pub reveal: Item<RevealState>,
+    /// Optional base uri, token uri is derived from it for NFTs without a token uri.
+    pub base_uri: Item<BaseUri>,
+    /// Optional max supply, enforced on minting.
+    pub max_supply: Item<MaxSupply>,
+    /// Once sealed, no more NFTs can be minted. NOTE: sealing is irreversible!
+    pub supply_sealed: Item<bool>,
+    /// Total number of minted NFTs, including burned ones.
+    pub total_minted: Item<u64>,
+    /// Tombstones of burned NFTs. By default, burned token ids can't be minted again.
+    pub burned_tokens: Map<&'a str, BurnInfo>,
+    /// If true, burned token ids can be minted again, and their tombstone is removed.
+    pub allow_burned_remint: Item<bool>,
+    /// Optional strategy for generating token ids on mint, used in case of an empty token id.
+    pub token_id_strategy: Item<TokenIdStrategy>,
+    /// Next token id to be generated.
+    pub next_token_id: Item<u64>,
+    /// Max number of ownership changes kept per NFT, history is disabled if not set or zero.
+    pub token_history_depth: Item<u32>,
+    /// Bounded history (ring buffer) of ownership changes per NFT, keyed by token id and sequence.
+    pub token_history: Map<(&'a str, u64), TokenHistoryEntry>,
+    /// Next sequence of token history per NFT.
+    pub token_history_seq: Map<&'a str, u64>,
+    /// Pending two-step transfers, indexed by recipient. NFT stays with the owner until the recipient accepts.
+    pub transfer_offers: IndexedMap<&'a str, TransferOffer, TransferOfferIndexes<'a>>,
+    /// Next permit nonce per owner. Each permit signed by the owner can only be used once.
+    pub permit_nonces: Map<&'a Addr, u64>,
+    /// Public key signing mint vouchers off-chain, set by minter. Lazy minting is disabled if not set.
+    pub voucher_signer: Item<Binary>,
+    /// Hashes of redeemed mint vouchers, each voucher can only be redeemed once.
+    pub redeemed_vouchers: Map<&'a [u8], Empty>,
+    /// Merkle root of the claim allowlist, set by minter. Claims are disabled if not set.
+    pub merkle_root: Item<HexBinary>,
+    /// Claimed leaves of the claim merkle tree, each leaf can only be claimed once.
+    pub claimed_leaves: Map<&'a [u8], Empty>,
+    /// Creator-managed contracts notified on mint, transfer, send and burn, see `Cw721HookMsg`.
+    pub hooks: Map<&'a Addr, HookOnError>,
+    /// Last token id of a legacy NFT info migration in progress, see `migrate_legacy_nft_info`.
+    pub legacy_nft_info_cursor: Item<String>,
+    /// Set once balances are in place: on instantiation, or by migration for older collections.
+    pub balances_migrated: Item<bool>,
 }
 
 impl<TNftExtension> Default for Cw721Config<'static, TNftExtension>
@@ -73,6 +148,48 @@ where
             "tokens",
             "tokens__owner",
             "withdraw_address",
+            "cw721_token_approvals",
+            "cw721_nft_metadata",
+            "cw721_balances",
+            "cw721_snapshots_enabled",
+            "cw721_snapshot_owners",
+            "cw721_snapshot_owners__checkpoints",
+            "cw721_snapshot_owners__changelog",
+            "cw721_snapshot_balances",
+            "cw721_snapshot_balances__checkpoints",
+            "cw721_snapshot_balances__changelog",
+            "cw721_snapshot_num_tokens",
+            "cw721_snapshot_num_tokens__checkpoints",
+            "cw721_snapshot_num_tokens__changelog",
+            "cw721_token_locks",
+            "cw721_token_locks__locker",
+            "cw721_pause_status",
+            "cw721_operator_filter_mode",
+            "cw721_operator_filter",
+            "cw721_frozen_nft_info",
+            "cw721_metadata_freeze",
+            "cw721_reveal",
+            "cw721_base_uri",
+            "cw721_max_supply",
+            "cw721_supply_sealed",
+            "cw721_total_minted",
+            "cw721_burned_tokens",
+            "cw721_allow_burned_remint",
+            "cw721_token_id_strategy",
+            "cw721_next_token_id",
+            "cw721_token_history_depth",
+            "cw721_token_history",
+            "cw721_token_history_seq",
+            "cw721_transfer_offers",
+            "cw721_transfer_offers__recipient",
+            "cw721_permit_nonces",
+            "cw721_voucher_signer",
+            "cw721_redeemed_vouchers",
+            "cw721_merkle_root",
+            "cw721_claimed_leaves",
+            "cw721_hooks",
+            "cw721_legacy_nft_info_cursor",
+            "cw721_balances_migrated",
         )
     }
 }
@@ -81,25 +198,131 @@ impl<'a, TNftExtension> Cw721Config<'a, TNftExtension>
 where
     TNftExtension: Cw721State,
 {
+    #[allow(clippy::too_many_arguments)]
     fn new(
         collection_info_key: &'static str,
         collection_info_extension_key: &'static str,
         num_tokens_key: &'static str,
         operator_key: &'static str,
-        nft_info_key: &'static str,
-        nft_info_owner_key: &'static str,
+        nft_owner_info_key: &'static str,
+        nft_owner_info_owner_key: &'static str,
         withdraw_address_key: &'static str,
+        token_approvals_key: &'static str,
+        nft_metadata_key: &'static str,
+        balances_key: &'static str,
+        snapshots_enabled_key: &'static str,
+        snapshot_owners_key: &'static str,
+        snapshot_owners_checkpoints_key: &'static str,
+        snapshot_owners_changelog_key: &'static str,
+        snapshot_balances_key: &'static str,
+        snapshot_balances_checkpoints_key: &'static str,
+        snapshot_balances_changelog_key: &'static str,
+        snapshot_num_tokens_key: &'static str,
+        snapshot_num_tokens_checkpoints_key: &'static str,
+        snapshot_num_tokens_changelog_key: &'static str,
+        token_locks_key: &'static str,
+        token_locks_locker_key: &'static str,
+        pause_status_key: &'static str,
+        operator_filter_mode_key: &'static str,
+        operator_filter_key: &'static str,
+        frozen_nft_info_key: &'static str,
+        metadata_freeze_key: &'static str,
+        reveal_key: &'static str,
+        base_uri_key: &'static str,
+        max_supply_key: &'static str,
+        supply_sealed_key: &'static str,
+        total_minted_key: &'static str,
+        burned_tokens_key: &'static str,
+        allow_burned_remint_key: &'static str,
+        token_id_strategy_key: &'static str,
+        next_token_id_key: &'static str,
+        token_history_depth_key: &'static str,
+        token_history_key: &'static str,
+        token_history_seq_key: &'static str,
+        transfer_offers_key: &'static str,
+        transfer_offers_recipient_key: &'static str,
+        permit_nonces_key: &'static str,
+        voucher_signer_key: &'static str,
+        redeemed_vouchers_key: &'static str,
+        merkle_root_key: &'static str,
+        claimed_leaves_key: &'static str,
+        hooks_key: &'static str,
+        legacy_nft_info_cursor_key: &'static str,
+        balances_migrated_key: &'static str,
     ) -> Self {
         let indexes = TokenIndexes {
-            owner: MultiIndex::new(token_owner_idx, nft_info_key, nft_info_owner_key),
+            owner: MultiIndex::new(
+                token_owner_idx,
+                nft_owner_info_key,
+                nft_owner_info_owner_key,
+            ),
+        };
+        let lock_indexes = LockIndexes {
+            locker: MultiIndex::new(token_locker_idx, token_locks_key, token_locks_locker_key),
+        };
+        let transfer_offer_indexes = TransferOfferIndexes {
+            recipient: MultiIndex::new(
+                transfer_offer_recipient_idx,
+                transfer_offers_key,
+                transfer_offers_recipient_key,
+            ),
         };
         Self {
             collection_info: Item::new(collection_info_key),
             num_tokens: Item::new(num_tokens_key),
             operators: Map::new(operator_key),
-            nft_info: IndexedMap::new(nft_info_key, indexes),
+            nft_owner_info: IndexedMap::new(nft_owner_info_key, indexes),
+            nft_metadata: Map::new(nft_metadata_key),
             withdraw_address: Item::new(withdraw_address_key),
             collection_extension: Map::new(collection_info_extension_key),
+            token_approvals: Map::new(token_approvals_key),
+            balances: Map::new(balances_key),
+            snapshots_enabled: Item::new(snapshots_enabled_key),
+            snapshot_owners: SnapshotMap::new(
+                snapshot_owners_key,
+                snapshot_owners_checkpoints_key,
+                snapshot_owners_changelog_key,
+                Strategy::EveryBlock,
+            ),
+            snapshot_balances: SnapshotMap::new(
+                snapshot_balances_key,
+                snapshot_balances_checkpoints_key,
+                snapshot_balances_changelog_key,
+                Strategy::EveryBlock,
+            ),
+            snapshot_num_tokens: SnapshotItem::new(
+                snapshot_num_tokens_key,
+                snapshot_num_tokens_checkpoints_key,
+                snapshot_num_tokens_changelog_key,
+                Strategy::EveryBlock,
+            ),
+            token_locks: IndexedMap::new(token_locks_key, lock_indexes),
+            pause_status: Item::new(pause_status_key),
+            operator_filter_mode: Item::new(operator_filter_mode_key),
+            operator_filter: Map::new(operator_filter_key),
+            frozen_nft_info: Map::new(frozen_nft_info_key),
+            metadata_freeze: Item::new(metadata_freeze_key),
+            reveal: Item::new(reveal_key),
+            base_uri: Item::new(base_uri_key),
+            max_supply: Item::new(max_supply_key),
+            supply_sealed: Item::new(supply_sealed_key),
+            total_minted: Item::new(total_minted_key),
+            burned_tokens: Map::new(burned_tokens_key),
+            allow_burned_remint: Item::new(allow_burned_remint_key),
+            token_id_strategy: Item::new(token_id_strategy_key),
+            next_token_id: Item::new(next_token_id_key),
+            token_history_depth: Item::new(token_history_depth_key),
+            token_history: Map::new(token_history_key),
+            token_history_seq: Map::new(token_history_seq_key),
+            transfer_offers: IndexedMap::new(transfer_offers_key, transfer_offer_indexes),
+            permit_nonces: Map::new(permit_nonces_key),
+            voucher_signer: Item::new(voucher_signer_key),
+            redeemed_vouchers: Map::new(redeemed_vouchers_key),
+            merkle_root: Item::new(merkle_root_key),
+            claimed_leaves: Map::new(claimed_leaves_key),
+            hooks: Map::new(hooks_key),
+            legacy_nft_info_cursor: Item::new(legacy_nft_info_cursor_key),
+            balances_migrated: Item::new(balances_migrated_key),
         }
     }
 
@@ -108,28 +331,339 @@ where
     }
 
     pub fn increment_tokens(&self, storage: &mut dyn Storage) -> StdResult<u64> {
-        let val = self.token_count(storage)? + 1;
+        self.increment_tokens_by(storage, 1)
+    }
+
+    pub fn decrement_tokens(&self, storage: &mut dyn Storage) -> StdResult<u64> {
+        self.decrement_tokens_by(storage, 1)
+    }
+
+    /// Used by batch messages, so `num_tokens` is only updated once.
+    pub fn increment_tokens_by(&self, storage: &mut dyn Storage, amount: u64) -> StdResult<u64> {
+        let val = self.token_count(storage)? + amount;
         self.num_tokens.save(storage, &val)?;
         Ok(val)
     }
 
-    pub fn decrement_tokens(&self, storage: &mut dyn Storage) -> StdResult<u64> {
-        let val = self.token_count(storage)? - 1;
+    /// Used by batch messages, so `num_tokens` is only updated once.
+    pub fn decrement_tokens_by(&self, storage: &mut dyn Storage, amount: u64) -> StdResult<u64> {
+        let val = self.token_count(storage)? - amount;
         self.num_tokens.save(storage, &val)?;
         Ok(val)
     }
+
+    /// Total number of minted NFTs, including burned ones.
+    pub fn minted_count(&self, storage: &dyn Storage) -> StdResult<u64> {
+        Ok(self.total_minted.may_load(storage)?.unwrap_or_default())
+    }
+
+    pub fn increment_minted(&self, storage: &mut dyn Storage) -> StdResult<u64> {
+        let val = self.minted_count(storage)? + 1;
+        self.total_minted.save(storage, &val)?;
+        Ok(val)
+    }
+
+    pub fn is_supply_sealed(&self, storage: &dyn Storage) -> StdResult<bool> {
+        Ok(self.supply_sealed.may_load(storage)?.unwrap_or(false))
+    }
+
+    pub fn balance_of(&self, storage: &dyn Storage, owner: &Addr) -> StdResult<u64> {
+        Ok(self.balances.may_load(storage, owner)?.unwrap_or_default())
+    }
+
+    pub fn increment_balance(&self, storage: &mut dyn Storage, owner: &Addr) -> StdResult<u64> {
+        let val = self.balance_of(storage, owner)? + 1;
+        self.balances.save(storage, owner, &val)?;
+        Ok(val)
+    }
+
+    /// Removes entry once balance drops to zero, so no empty balances are kept in storage.
+    pub fn decrement_balance(&self, storage: &mut dyn Storage, owner: &Addr) -> StdResult<u64> {
+        let val = self.balance_of(storage, owner)?.saturating_sub(1);
+        if val == 0 {
+            self.balances.remove(storage, owner);
+        } else {
+            self.balances.save(storage, owner, &val)?;
+        }
+        Ok(val)
+    }
+
+    pub fn has_snapshots(&self, storage: &dyn Storage) -> StdResult<bool> {
+        Ok(self
+            .snapshots_enabled
+            .may_load(storage)?
+            .unwrap_or_default())
+    }
+
+    /// Records an ownership change at given height, in case snapshots are enabled.
+    /// `previous_owner` is `None` on mint, and `new_owner` is `None` on burn.
+    pub fn snapshot_ownership(
+        &self,
+        storage: &mut dyn Storage,
+        height: u64,
+        token_id: &str,
+        previous_owner: Option<&Addr>,
+        new_owner: Option<&Addr>,
+    ) -> StdResult<()> {
+        if !self.has_snapshots(storage)? {
+            return Ok(());
+        }
+        match new_owner {
+            Some(owner) => self
+                .snapshot_owners
+                .save(storage, token_id, owner, height)?,
+            None => self.snapshot_owners.remove(storage, token_id, height)?,
+        }
+        if let Some(owner) = previous_owner {
+            let balance = self
+                .snapshot_balances
+                .may_load(storage, owner)?
+                .unwrap_or_default();
+            self.snapshot_balances
+                .save(storage, owner, &balance.saturating_sub(1), height)?;
+        }
+        if let Some(owner) = new_owner {
+            let balance = self
+                .snapshot_balances
+                .may_load(storage, owner)?
+                .unwrap_or_default();
+            self.snapshot_balances
+                .save(storage, owner, &(balance + 1), height)?;
+        }
+        let num_tokens = self
+            .snapshot_num_tokens
+            .may_load(storage)?
+            .unwrap_or_default();
+        match (previous_owner, new_owner) {
+            (None, Some(_)) => self
+                .snapshot_num_tokens
+                .save(storage, &(num_tokens + 1), height),
+            (Some(_), None) => {
+                self.snapshot_num_tokens
+                    .save(storage, &num_tokens.saturating_sub(1), height)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Records an ownership change in token history, in case history is enabled.
+    /// Once depth is exceeded, the oldest entry is dropped.
+    pub fn record_token_history(
+        &self,
+        storage: &mut dyn Storage,
+        token_id: &str,
+        entry: &TokenHistoryEntry,
+    ) -> StdResult<()> {
+        let depth = self
+            .token_history_depth
+            .may_load(storage)?
+            .unwrap_or_default() as u64;
+        if depth == 0 {
+            return Ok(());
+        }
+        let seq = self
+            .token_history_seq
+            .may_load(storage, token_id)?
+            .unwrap_or_default();
+        self.token_history.save(storage, (token_id, seq), entry)?;
+        if seq >= depth {
+            self.token_history.remove(storage, (token_id, seq - depth));
+        }
+        self.token_history_seq.save(storage, token_id, &(seq + 1))
+    }
+
+    pub fn load_pause_status(&self, storage: &dyn Storage) -> StdResult<PauseStatus> {
+        Ok(self.pause_status.may_load(storage)?.unwrap_or_default())
+    }
+
+    /// Checks operator against allowlist or blocklist. All operators are allowed if no filter is set.
+    pub fn is_operator_allowed(&self, storage: &dyn Storage, operator: &Addr) -> StdResult<bool> {
+        match self.operator_filter_mode.may_load(storage)? {
+            Some(OperatorFilterMode::Allowlist) => Ok(self.operator_filter.has(storage, operator)),
+            Some(OperatorFilterMode::Blocklist) => Ok(!self.operator_filter.has(storage, operator)),
+            None => Ok(true),
+        }
+    }
+
+    pub fn load_metadata_freeze(&self, storage: &dyn Storage) -> StdResult<MetadataFreeze> {
+        Ok(self.metadata_freeze.may_load(storage)?.unwrap_or_default())
+    }
+
+    /// NFT info is frozen, in case either all NFT infos or the given one is frozen.
+    pub fn is_nft_info_frozen(&self, storage: &dyn Storage, token_id: &str) -> StdResult<bool> {
+        Ok(self.load_metadata_freeze(storage)?.all_nft_info
+            || self.frozen_nft_info.has(storage, token_id))
+    }
+
+    /// Resolves the token uri of an NFT. The NFT's own token uri always takes precedence.
+    /// Otherwise, in case a reveal is committed, the placeholder uri is returned until revealed.
+    /// Once revealed, the token uri is derived from the revealed base uri, the token id and the
+    /// suffix of the collection's base uri (if set).
+    /// Without reveal, the token uri is derived from the collection's base uri (if set).
+    pub fn resolve_token_uri(
+        &self,
+        storage: &dyn Storage,
+        token_id: &str,
+        token_uri: Option<String>,
+    ) -> StdResult<Option<String>> {
+        if token_uri.is_some() {
+            return Ok(token_uri);
+        }
+        let base_uri = self.base_uri.may_load(storage)?;
+        match self.reveal.may_load(storage)? {
+            Some(RevealState {
+                base_uri: Some(revealed_base_uri),
+                ..
+            }) => Ok(Some(
+                BaseUri {
+                    uri: revealed_base_uri,
+                    suffix: base_uri.and_then(|base_uri| base_uri.suffix),
+                }
+                .token_uri(token_id),
+            )),
+            Some(reveal) => Ok(Some(reveal.placeholder_uri)),
+            None => Ok(base_uri.map(|base_uri| base_uri.token_uri(token_id))),
+        }
+    }
+
+    /// Loads ownership record and metadata of an NFT.
+    pub fn load_nft_info(
+        &self,
+        storage: &dyn Storage,
+        token_id: &str,
+    ) -> StdResult<NftInfo<TNftExtension>> {
+        let NftOwnerInfo {
+            owner, provenance, ..
+        } = self.nft_owner_info.load(storage, token_id)?;
+        let NftMetadata {
+            token_uri,
+            extension,
+        } = self.nft_metadata.load(storage, token_id)?;
+        Ok(NftInfo {
+            owner,
+            token_uri,
+            extension,
+            provenance,
+        })
+    }
+
+    /// Saves ownership record and metadata of an NFT.
+    pub fn save_nft_info(
+        &self,
+        storage: &mut dyn Storage,
+        token_id: &str,
+        nft_info: &NftInfo<TNftExtension>,
+    ) -> StdResult<()> {
+        self.nft_owner_info.save(
+            storage,
+            token_id,
+            &NftOwnerInfo {
+                owner: nft_info.owner.clone(),
+                user: None,
+                provenance: nft_info.provenance.clone(),
+            },
+        )?;
+        self.nft_metadata.save(
+            storage,
+            token_id,
+            &NftMetadata {
+                token_uri: nft_info.token_uri.clone(),
+                extension: nft_info.extension.clone(),
+            },
+        )
+    }
+
+    /// Removes ownership record, metadata (incl. freeze) and approvals of an NFT.
+    pub fn remove_nft_info(&self, storage: &mut dyn Storage, token_id: &str) -> StdResult<()> {
+        self.nft_owner_info.remove(storage, token_id)?;
+        self.nft_metadata.remove(storage, token_id);
+        self.frozen_nft_info.remove(storage, token_id);
+        self.transfer_offers.remove(storage, token_id)?;
+        self.clear_token_approvals(storage, token_id)
+    }
+
+    /// Returns all approvals of an NFT, including expired ones.
+    pub fn load_token_approvals(
+        &self,
+        storage: &dyn Storage,
+        token_id: &str,
+    ) -> StdResult<Vec<Approval>> {
+        self.token_approvals
+            .prefix(token_id)
+            .range(storage, None, None, Order::Ascending)
+            .map(|item| item.map(|(spender, expires)| Approval { spender, expires }))
+            .collect()
+    }
+
+    /// Removes all approvals of an NFT, e.g. on transfer and burn.
+    pub fn clear_token_approvals(
+        &self,
+        storage: &mut dyn Storage,
+        token_id: &str,
+    ) -> StdResult<()> {
+        let spenders = self
+            .token_approvals
+            .prefix(token_id)
+            .keys(storage, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<Addr>>>()?;
+        for spender in spenders {
+            self.token_approvals.remove(storage, (token_id, &spender));
+        }
+        Ok(())
+    }
 }
 
-pub fn token_owner_idx<TNftExtension>(_pk: &[u8], d: &NftInfo<TNftExtension>) -> Addr {
+pub fn token_owner_idx(_pk: &[u8], d: &NftOwnerInfo) -> Addr {
     d.owner.clone()
 }
 
+/// Ownership record of an NFT, without any metadata.
+#[cw_serde]
+pub struct NftOwnerInfo {
+    pub owner: Addr,
+    /// Optional user with usage rights (ERC-4907), while owner keeps custody.
+    /// NOTE: cleared on transfer and burn!
+    pub user: Option<NftUser>,
+    /// Defaults to empty provenance for NFTs stored before provenance was introduced.
+    #[serde(default)]
+    pub provenance: NftProvenance,
+}
+
+/// Provenance of an NFT, for NFTs minted before provenance was introduced fields are `None`.
+#[cw_serde]
+#[derive(Default)]
+pub struct NftProvenance {
+    pub minted_by: Option<Addr>,
+    pub minted_at: Option<Timestamp>,
+    pub last_transferred_at: Option<Timestamp>,
+}
+
+#[cw_serde]
+pub struct NftUser {
+    /// Account that has usage rights of the token
+    pub user: Addr,
+    /// When usage rights expire (maybe Expiration::never)
+    pub expires: Expiration,
+}
+
+impl NftUser {
+    pub fn is_expired(&self, block: &BlockInfo) -> bool {
+        self.expires.is_expired(block)
+    }
+}
+
+/// Metadata of an NFT, stored separately from its ownership record.
+#[cw_serde]
+pub struct NftMetadata<TNftExtension> {
+    pub token_uri: Option<String>,
+    pub extension: TNftExtension,
+}
+
+/// NFT info, consisting of `NftOwnerInfo` and `NftMetadata`.
 #[cw_serde]
 pub struct NftInfo<TNftExtension> {
     /// The owner of the newly minted NFT
     pub owner: Addr,
-    /// Approvals are stored here, as we clear them all upon transfer and cannot accumulate much
-    pub approvals: Vec<Approval>,
 
     /// Universal resource identifier for this NFT
     /// Should point to a JSON file that conforms to the ERC721
@@ -138,6 +672,20 @@ pub struct NftInfo<TNftExtension> {
 
     /// You can add any custom metadata here when you extend cw721-base
     pub extension: TNftExtension,
+
+    /// Who minted the NFT and when, and when it was last transferred
+    pub provenance: NftProvenance,
+}
+
+/// Legacy `NftInfo`, where metadata and approvals were stored within the ownership record.
+/// Only used for migration.
+#[cw_serde]
+pub struct LegacyNftInfo<TNftExtension> {
+    pub owner: Addr,
+    /// Only set in case approvals are not yet moved to `Cw721Config::token_approvals`.
+    pub approvals: Option<Vec<Approval>>,
+    pub token_uri: Option<String>,
+    pub extension: TNftExtension,
 }
 
 #[cw_serde]
@@ -154,21 +702,200 @@ impl Approval {
     }
 }
 
-pub struct TokenIndexes<'a, TNftExtension>
-where
-    TNftExtension: Cw721State,
-{
-    pub owner: MultiIndex<'a, Addr, NftInfo<TNftExtension>, String>,
+pub struct TokenIndexes<'a> {
+    pub owner: MultiIndex<'a, Addr, NftOwnerInfo, String>,
 }
 
-impl<'a, TNftExtension> IndexList<NftInfo<TNftExtension>> for TokenIndexes<'a, TNftExtension>
-where
-    TNftExtension: Cw721State,
-{
-    fn get_indexes(
-        &'_ self,
-    ) -> Box<dyn Iterator<Item = &'_ dyn Index<NftInfo<TNftExtension>>> + '_> {
-        let v: Vec<&dyn Index<NftInfo<TNftExtension>>> = vec![&self.owner];
+impl<'a> IndexList<NftOwnerInfo> for TokenIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<NftOwnerInfo>> + '_> {
+        let v: Vec<&dyn Index<NftOwnerInfo>> = vec![&self.owner];
+        Box::new(v.into_iter())
+    }
+}
+
+#[cw_serde]
+pub enum TokenHistoryAction {
+    Mint,
+    Transfer,
+    Send,
+    Burn,
+}
+
+#[cw_serde]
+pub struct TokenHistoryEntry {
+    pub action: TokenHistoryAction,
+    /// `None` on mint
+    pub from: Option<Addr>,
+    /// `None` on burn
+    pub to: Option<Addr>,
+    pub height: u64,
+    pub time: Timestamp,
+}
+
+#[cw_serde]
+pub enum TokenIdStrategy {
+    /// Sequential token ids starting at `offset`. Optionally zero-padded to `padding` digits,
+    /// e.g. `0042` for a padding of 4.
+    Sequential { offset: u64, padding: Option<u8> },
+}
+
+#[cw_serde]
+pub struct BurnInfo {
+    pub burned_by: Addr,
+    pub burned_at: Timestamp,
+}
+
+#[cw_serde]
+pub struct MaxSupply {
+    /// Max number of NFTs
+    pub limit: u64,
+    /// If true, burned NFTs are counted as well (total minted), so burning doesn't free up supply.
+    /// Otherwise, limit is checked against the current number of NFTs.
+    pub include_burned: bool,
+}
+
+/// Base uri mode: token uri is derived from base uri, token id and optional suffix,
+/// e.g. `ipfs://<cid>/` + `1` + `.json`.
+#[cw_serde]
+pub struct BaseUri {
+    pub uri: String,
+    pub suffix: Option<String>,
+}
+
+impl BaseUri {
+    pub fn token_uri(&self, token_id: &str) -> String {
+        format!(
+            "{}{}{}",
+            self.uri,
+            token_id,
+            self.suffix.as_deref().unwrap_or_default()
+        )
+    }
+}
+
+#[cw_serde]
+pub struct RevealState {
+    /// sha256 hash of the length prefixed final base uri and a salt
+    pub commitment: HexBinary,
+    /// Token uri returned for all NFTs until revealed
+    pub placeholder_uri: String,
+    /// Final base uri, set once revealed
+    pub base_uri: Option<String>,
+}
+
+#[cw_serde]
+#[derive(Default)]
+pub struct MetadataFreeze {
+    pub all_nft_info: bool,
+    pub collection_info: bool,
+    pub base_uri: bool,
+}
+
+/// Behaviour in case a hook fails.
+#[cw_serde]
+pub enum HookOnError {
+    /// Whole transaction fails.
+    Fail,
+    /// Error is ignored, so a faulty hook can't block mints, transfers, sends and burns.
+    Ignore,
+}
+
+#[cw_serde]
+pub enum OperatorFilterMode {
+    /// Only listed operators are allowed.
+    Allowlist,
+    /// All operators except listed ones are allowed.
+    Blocklist,
+}
+
+/// Activities that can be paused independently.
+#[cw_serde]
+pub enum PauseScope {
+    /// Mint and batch mint
+    Minting,
+    /// Transfers and sends, including batches
+    Transfers,
+    /// Approve and approve all. Revokes are always allowed.
+    Approvals,
+    /// NFT info and collection info updates
+    Metadata,
+}
+
+impl fmt::Display for PauseScope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PauseScope::Minting => write!(f, "minting"),
+            PauseScope::Transfers => write!(f, "transfers"),
+            PauseScope::Approvals => write!(f, "approvals"),
+            PauseScope::Metadata => write!(f, "metadata"),
+        }
+    }
+}
+
+#[cw_serde]
+#[derive(Default)]
+pub struct PauseStatus {
+    pub minting: bool,
+    pub transfers: bool,
+    pub approvals: bool,
+    pub metadata: bool,
+}
+
+impl PauseStatus {
+    pub fn is_paused(&self, scope: &PauseScope) -> bool {
+        match scope {
+            PauseScope::Minting => self.minting,
+            PauseScope::Transfers => self.transfers,
+            PauseScope::Approvals => self.approvals,
+            PauseScope::Metadata => self.metadata,
+        }
+    }
+
+    pub fn set_paused(&mut self, scope: &PauseScope, paused: bool) {
+        match scope {
+            PauseScope::Minting => self.minting = paused,
+            PauseScope::Transfers => self.transfers = paused,
+            PauseScope::Approvals => self.approvals = paused,
+            PauseScope::Metadata => self.metadata = paused,
+        }
+    }
+}
+
+pub fn token_locker_idx(_pk: &[u8], d: &Addr) -> Addr {
+    d.clone()
+}
+
+pub struct LockIndexes<'a> {
+    pub locker: MultiIndex<'a, Addr, Addr, String>,
+}
+
+impl<'a> IndexList<Addr> for LockIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<Addr>> + '_> {
+        let v: Vec<&dyn Index<Addr>> = vec![&self.locker];
+        Box::new(v.into_iter())
+    }
+}
+
+/// Pending offer to transfer an NFT, accepted by the recipient.
+#[cw_serde]
+pub struct TransferOffer {
+    /// Owner, approved spender or operator who made the offer.
+    pub offered_by: Addr,
+    pub recipient: Addr,
+    pub expires: Expiration,
+}
+
+pub fn transfer_offer_recipient_idx(_pk: &[u8], d: &TransferOffer) -> Addr {
+    d.recipient.clone()
+}
+
+pub struct TransferOfferIndexes<'a> {
+    pub recipient: MultiIndex<'a, Addr, TransferOffer, String>,
+}
+
+impl<'a> IndexList<TransferOffer> for TransferOfferIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<TransferOffer>> + '_> {
+        let v: Vec<&dyn Index<TransferOffer>> = vec![&self.recipient];
         Box::new(v.into_iter())
     }
 }
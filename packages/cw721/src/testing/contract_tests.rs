@@ -1,26 +1,49 @@
 use cosmwasm_std::testing::{message_info, mock_dependencies, mock_env, MockApi};
 
 use cosmwasm_std::{
-    from_json, to_json_binary, Addr, Coin, CosmosMsg, DepsMut, Empty, MessageInfo, Response,
-    StdError, Timestamp, WasmMsg,
+    from_json, to_json_binary, Addr, Api, Attribute, Binary, Coin, CosmosMsg, Deps, DepsMut, Empty,
+    Env, Event, HexBinary, MessageInfo, Reply, Response, StdError, SubMsg, SubMsgResult, Timestamp,
+    Uint128, WasmMsg,
 };
 
 use crate::error::Cw721ContractError;
+use crate::execute::LEGACY_NFT_INFO_MIGRATION_LIMIT;
 use crate::extension::Cw721OnchainExtensions;
+use crate::helpers::{
+    claim_leaf, merkle_parent, permit_hash, pubkey_to_canonical_addr, reveal_commitment,
+    voucher_hash,
+};
+use crate::hook::{Cw721HookMsg, HOOK_REPLY_ID};
+use crate::msg::{
+    ApprovalResponse, BalanceOfResponse, BatchMintResponse, BurnedToken, BurnedTokensResponse,
+    CollectionExtensionMsg, FreezeStatusResponse, Hook, HooksResponse, IsBurnedResponse,
+    IsClaimedResponse, IsVoucherRedeemedResponse, LockStatusResponse, MintResponse, MintVoucher,
+    NftExtensionMsg, NftInfoResponse, OperatorFilterResponse, OperatorResponse, OperatorsResponse,
+    OwnerOfAtHeightResponse, OwnerOfResponse, Permit, PermitAction, PermitNonceResponse,
+    RevealStatusResponse, RoyaltiesInfoResponse, RoyaltyInfoResponse, TokenHistoryItem,
+    TokensResponse, TransferOfferInfo, TransferOfferResponse, TransferOffersResponse,
+    UserOfResponse,
+};
 use crate::msg::{
-    ApprovalResponse, CollectionExtensionMsg, NftExtensionMsg, NftInfoResponse, OperatorResponse,
-    OperatorsResponse, OwnerOfResponse, RoyaltyInfoResponse,
+    CollectionInfoMsg, Cw721ExecuteMsg, Cw721InstantiateMsg, Cw721MigrateMsg, Cw721QueryMsg,
+    MintMsg,
 };
-use crate::msg::{CollectionInfoMsg, Cw721ExecuteMsg, Cw721InstantiateMsg, Cw721QueryMsg};
 use crate::receiver::Cw721ReceiveMsg;
-use crate::state::{NftExtension, Trait, CREATOR, MINTER};
+use crate::state::{
+    BaseUri, HookOnError, LegacyNftInfo, MaxSupply, NftExtension, NftProvenance,
+    OperatorFilterMode, PauseScope, PauseStatus, TokenHistoryAction, TokenIdStrategy, Trait,
+    CREATOR, MINTER,
+};
 use crate::{
     traits::{Cw721Execute, Cw721Query},
-    Approval, DefaultOptionalCollectionExtensionMsg, DefaultOptionalNftExtension,
-    DefaultOptionalNftExtensionMsg, Expiration,
+    Approval, DefaultOptionalCollectionExtension, DefaultOptionalCollectionExtensionMsg,
+    DefaultOptionalNftExtension, DefaultOptionalNftExtensionMsg, Expiration,
 };
 use crate::{CollectionExtension, CollectionInfoAndExtensionResponse, RoyaltyInfo};
 use cw_ownable::{get_ownership, Action, Ownership, OwnershipError};
+use cw_storage_plus::Map;
+use cw_utils::PaymentError;
+use k256::ecdsa::{signature::hazmat::PrehashSigner, Signature, SigningKey};
 
 const CONTRACT_NAME: &str = "Magic Power";
 const SYMBOL: &str = "MGK";
@@ -63,6 +86,13 @@ fn setup_contract(
         minter: Some(minter.to_string()),
         creator: Some(creator.to_string()),
         withdraw_address: None,
+        enable_snapshots: None,
+        allow_burned_remint: None,
+        token_history_depth: None,
+        token_id_strategy: None,
+        max_supply: None,
+        base_uri: None,
+        reveal: None,
     };
     let info_creator = message_info(creator, &[]);
     let res = contract
@@ -93,6 +123,13 @@ fn test_instantiate() {
         minter: Some(minter.to_string()),
         creator: Some(creator.to_string()),
         withdraw_address: Some(creator.to_string()),
+        enable_snapshots: None,
+        allow_burned_remint: None,
+        token_history_depth: None,
+        token_id_strategy: None,
+        max_supply: None,
+        base_uri: None,
+        reveal: None,
     };
     let info = addrs.info("creator");
     let env = mock_env();
@@ -124,7 +161,9 @@ fn test_instantiate() {
             name: CONTRACT_NAME.to_string(),
             symbol: SYMBOL.to_string(),
             extension: None,
-            updated_at: env.block.time
+            updated_at: env.block.time,
+            max_supply: None,
+            supply_sealed: false,
         }
     );
 
@@ -171,6 +210,13 @@ fn test_instantiate_with_collection_info_and_extension() {
         minter: Some(minter.to_string()),
         creator: Some(creator.to_string()),
         withdraw_address: Some(creator.to_string()),
+        enable_snapshots: None,
+        allow_burned_remint: None,
+        token_history_depth: None,
+        token_id_strategy: None,
+        max_supply: None,
+        base_uri: None,
+        reveal: None,
     };
     let info = addrs.info("creator");
     let env = mock_env();
@@ -213,7 +259,9 @@ fn test_instantiate_with_collection_info_and_extension() {
             name: CONTRACT_NAME.to_string(),
             symbol: SYMBOL.to_string(),
             extension: collection_info_extension_expected,
-            updated_at: env.block.time
+            updated_at: env.block.time,
+            max_supply: None,
+            supply_sealed: false,
         }
     );
 
@@ -256,6 +304,13 @@ fn test_instantiate_with_minimal_collection_info_and_extension() {
         minter: Some(minter.to_string()),
         creator: Some(creator.to_string()),
         withdraw_address: Some(creator.to_string()),
+        enable_snapshots: None,
+        allow_burned_remint: None,
+        token_history_depth: None,
+        token_id_strategy: None,
+        max_supply: None,
+        base_uri: None,
+        reveal: None,
     };
     let info = addrs.info("creator");
     let env = mock_env();
@@ -295,7 +350,9 @@ fn test_instantiate_with_minimal_collection_info_and_extension() {
             name: CONTRACT_NAME.to_string(),
             symbol: SYMBOL.to_string(),
             extension: collection_info_extension_expected,
-            updated_at: env.block.time
+            updated_at: env.block.time,
+            max_supply: None,
+            supply_sealed: false,
         }
     );
 }
@@ -1348,186 +1405,4079 @@ fn test_send_nft() {
 }
 
 #[test]
-fn test_approve_revoke() {
+fn test_batch_mint() {
     let mut deps = mock_dependencies();
     let mut addrs = MockAddrFactory::new(deps.api);
     let creator = addrs.addr("creator");
     let minter = addrs.addr("minter");
     let contract = setup_contract(deps.as_mut(), &creator, &minter);
+    let venus = addrs.addr("venus");
 
-    // Mint a token
-    let token_id = "grow".to_string();
-    let token_uri = "https://www.merriam-webster.com/dictionary/grow".to_string();
-
-    let mint_msg = Cw721ExecuteMsg::Mint {
-        token_id: token_id.clone(),
-        owner: addrs.addr("demeter").to_string(),
-        token_uri: Some(token_uri),
-        extension: None,
+    let batch_mint_msg = Cw721ExecuteMsg::BatchMint {
+        tokens: vec![
+            MintMsg {
+                token_id: "melt".to_string(),
+                owner: venus.to_string(),
+                token_uri: Some("https://www.merriam-webster.com/dictionary/melt".to_string()),
+                extension: None,
+            },
+            MintMsg {
+                token_id: "grow".to_string(),
+                owner: venus.to_string(),
+                token_uri: None,
+                extension: None,
+            },
+        ],
     };
 
-    let minter_info = addrs.info("minter");
-    contract
-        .execute(deps.as_mut(), &mock_env(), &minter_info, mint_msg)
-        .unwrap();
+    // random cannot mint
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("random"),
+            batch_mint_msg.clone(),
+        )
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::NotMinter {});
 
-    // token owner shows in approval query
+    // minter can
     let res = contract
-        .query_approval(
-            deps.as_ref(),
+        .execute(
+            deps.as_mut(),
             &mock_env(),
-            token_id.clone(),
-            addrs.addr("demeter").to_string(),
-            false,
+            &addrs.info("minter"),
+            batch_mint_msg.clone(),
         )
         .unwrap();
     assert_eq!(
         res,
-        ApprovalResponse {
-            approval: Approval {
-                spender: addrs.addr("demeter"),
-                expires: Expiration::Never {}
-            }
-        }
+        Response::new()
+            .set_data(
+                to_json_binary(&BatchMintResponse {
+                    token_ids: vec!["melt".to_string(), "grow".to_string()],
+                })
+                .unwrap()
+            )
+            .add_event(
+                Event::new("mint")
+                    .add_attribute("owner", venus.to_string())
+                    .add_attribute("token_id", "melt")
+                    .add_attribute(
+                        "token_uri",
+                        "https://www.merriam-webster.com/dictionary/melt"
+                    )
+            )
+            .add_event(
+                Event::new("mint")
+                    .add_attribute("owner", venus.to_string())
+                    .add_attribute("token_id", "grow")
+            )
+            .add_attribute("action", "batch_mint")
+            .add_attribute("minter", minter.to_string())
     );
+    let count = contract.query_num_tokens(deps.as_ref().storage).unwrap();
+    assert_eq!(2, count.count);
 
-    // Give random transferring power
-    let approve_msg = Cw721ExecuteMsg::Approve {
-        spender: addrs.addr("random").to_string(),
-        token_id: token_id.clone(),
-        expires: None,
+    // all-or-nothing: one existing token id fails the whole batch
+    let batch_mint_msg = Cw721ExecuteMsg::BatchMint {
+        tokens: vec![
+            MintMsg {
+                token_id: "sing".to_string(),
+                owner: venus.to_string(),
+                token_uri: None,
+                extension: None,
+            },
+            MintMsg {
+                token_id: "melt".to_string(),
+                owner: venus.to_string(),
+                token_uri: None,
+                extension: None,
+            },
+        ],
     };
-    let owner_info = addrs.info("demeter");
-    let res = contract
-        .execute(deps.as_mut(), &mock_env(), &owner_info, approve_msg)
-        .unwrap();
-    assert_eq!(
-        res,
-        Response::new()
-            .add_attribute("action", "approve")
-            .add_attribute("sender", addrs.addr("demeter").to_string())
-            .add_attribute("spender", addrs.addr("random").to_string())
-            .add_attribute("token_id", token_id.clone())
-    );
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("minter"),
+            batch_mint_msg,
+        )
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::Claimed {});
+}
 
-    // test approval query
+#[test]
+fn test_batch_transfer_and_send_nft() {
+    let mut deps = mock_dependencies();
+    let mut addrs = MockAddrFactory::new(deps.api);
+    let creator = addrs.addr("creator");
+    let minter = addrs.addr("minter");
+    let contract = setup_contract(deps.as_mut(), &creator, &minter);
+    let venus = addrs.addr("venus");
+    let random = addrs.addr("random");
+
+    for token_id in ["melt", "grow", "sing"] {
+        let mint_msg = Cw721ExecuteMsg::Mint {
+            token_id: token_id.to_string(),
+            owner: venus.to_string(),
+            token_uri: None,
+            extension: None,
+        };
+        contract
+            .execute(deps.as_mut(), &mock_env(), &addrs.info("minter"), mint_msg)
+            .unwrap();
+    }
+
+    // random cannot transfer
+    let transfer_msg = Cw721ExecuteMsg::BatchTransferNft {
+        recipient: random.to_string(),
+        token_ids: vec!["melt".to_string(), "grow".to_string()],
+    };
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("random"),
+            transfer_msg.clone(),
+        )
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::Ownership(OwnershipError::NotOwner));
+
+    // owner can
     let res = contract
-        .query_approval(
-            deps.as_ref(),
+        .execute(
+            deps.as_mut(),
             &mock_env(),
-            token_id.clone(),
-            addrs.addr("random").to_string(),
-            true,
+            &addrs.info("venus"),
+            transfer_msg,
         )
         .unwrap();
     assert_eq!(
         res,
-        ApprovalResponse {
-            approval: Approval {
-                spender: addrs.addr("random"),
-                expires: Expiration::Never {}
-            }
-        }
+        Response::new()
+            .add_event(Event::new("transfer_nft").add_attribute("token_id", "melt"))
+            .add_event(Event::new("transfer_nft").add_attribute("token_id", "grow"))
+            .add_attribute("action", "batch_transfer_nft")
+            .add_attribute("sender", venus.to_string())
+            .add_attribute("recipient", random.to_string())
     );
+    let tokens = contract
+        .query_tokens(deps.as_ref(), &mock_env(), random.to_string(), None, None)
+        .unwrap();
+    assert_eq!(tokens.tokens, vec!["grow".to_string(), "melt".to_string()]);
 
-    // random can now transfer
-    let random = addrs.addr("random");
-    let random_info = addrs.info("random");
-    let person = addrs.addr("person");
-    let transfer_msg = Cw721ExecuteMsg::TransferNft {
-        recipient: person.to_string(),
-        token_id: token_id.clone(),
+    // venus no longer owns "melt", so the whole batch fails
+    let msg = to_json_binary("You now have the melting power").unwrap();
+    let target = addrs.addr("another_contract");
+    let send_msg = Cw721ExecuteMsg::BatchSendNft {
+        contract: target.to_string(),
+        token_ids: vec!["melt".to_string(), "sing".to_string()],
+        msg: msg.clone(),
     };
-    contract
-        .execute(deps.as_mut(), &mock_env(), &random_info, transfer_msg)
-        .unwrap();
+    let err = contract
+        .execute(deps.as_mut(), &mock_env(), &addrs.info("venus"), send_msg)
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::Ownership(OwnershipError::NotOwner));
 
-    // Approvals are removed / cleared
-    let query_msg = Cw721QueryMsg::OwnerOf {
-        token_id: token_id.clone(),
-        include_expired: None,
+    let send_msg = Cw721ExecuteMsg::BatchSendNft {
+        contract: target.to_string(),
+        token_ids: vec!["sing".to_string()],
+        msg: msg.clone(),
+    };
+    let res = contract
+        .execute(deps.as_mut(), &mock_env(), &addrs.info("venus"), send_msg)
+        .unwrap();
+    let payload = Cw721ReceiveMsg {
+        sender: venus.to_string(),
+        token_id: "sing".to_string(),
+        msg,
     };
-    let res: OwnerOfResponse = from_json(
-        contract
-            .query(deps.as_ref(), &mock_env(), query_msg.clone())
-            .unwrap(),
-    )
-    .unwrap();
     assert_eq!(
         res,
-        OwnerOfResponse {
-            owner: person.to_string(),
-            approvals: vec![],
-        }
+        Response::new()
+            .add_message(payload.into_cosmos_msg(target.clone()).unwrap())
+            .add_event(Event::new("send_nft").add_attribute("token_id", "sing"))
+            .add_attribute("action", "batch_send_nft")
+            .add_attribute("sender", venus.to_string())
+            .add_attribute("recipient", target.to_string())
     );
+}
 
-    // Approve, revoke, and check for empty, to test revoke
-    let approve_msg = Cw721ExecuteMsg::Approve {
-        spender: random.to_string(),
-        token_id: token_id.clone(),
-        expires: None,
-    };
-    let owner_info = addrs.info("person");
-    contract
-        .execute(deps.as_mut(), &mock_env(), &owner_info, approve_msg)
-        .unwrap();
+#[test]
+fn test_batch_burn() {
+    let mut deps = mock_dependencies();
+    let mut addrs = MockAddrFactory::new(deps.api);
+    let creator = addrs.addr("creator");
+    let minter = addrs.addr("minter");
+    let contract = setup_contract(deps.as_mut(), &creator, &minter);
+    let venus = addrs.addr("venus");
 
-    let revoke_msg = Cw721ExecuteMsg::Revoke {
-        spender: addrs.addr("random").to_string(),
-        token_id,
+    for token_id in ["melt", "grow", "sing"] {
+        let mint_msg = Cw721ExecuteMsg::Mint {
+            token_id: token_id.to_string(),
+            owner: venus.to_string(),
+            token_uri: None,
+            extension: None,
+        };
+        contract
+            .execute(deps.as_mut(), &mock_env(), &addrs.info("minter"), mint_msg)
+            .unwrap();
+    }
+
+    // random not allowed to burn
+    let burn_msg = Cw721ExecuteMsg::BatchBurn {
+        token_ids: vec!["melt".to_string(), "grow".to_string()],
     };
-    contract
-        .execute(deps.as_mut(), &mock_env(), &owner_info, revoke_msg)
-        .unwrap();
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("random"),
+            burn_msg.clone(),
+        )
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::Ownership(OwnershipError::NotOwner));
 
-    // Approvals are now removed / cleared
-    let res: OwnerOfResponse = from_json(
-        contract
-            .query(deps.as_ref(), &mock_env(), query_msg)
-            .unwrap(),
-    )
-    .unwrap();
+    let res = contract
+        .execute(deps.as_mut(), &mock_env(), &addrs.info("venus"), burn_msg)
+        .unwrap();
     assert_eq!(
         res,
-        OwnerOfResponse {
-            owner: person.to_string(),
-            approvals: vec![],
-        }
+        Response::new()
+            .add_event(Event::new("burn").add_attribute("token_id", "melt"))
+            .add_event(Event::new("burn").add_attribute("token_id", "grow"))
+            .add_attribute("action", "batch_burn")
+            .add_attribute("sender", venus.to_string())
     );
+
+    // ensure num tokens decreases
+    let count = contract.query_num_tokens(deps.as_ref().storage).unwrap();
+    assert_eq!(1, count.count);
+    let tokens = contract
+        .query_all_tokens(deps.as_ref(), &mock_env(), None, None)
+        .unwrap();
+    assert_eq!(tokens.tokens, vec!["sing".to_string()]);
 }
 
 #[test]
-fn test_approve_all_revoke_all() {
+fn test_balance_of() {
     let mut deps = mock_dependencies();
     let mut addrs = MockAddrFactory::new(deps.api);
     let creator = addrs.addr("creator");
     let minter = addrs.addr("minter");
     let contract = setup_contract(deps.as_mut(), &creator, &minter);
+    let venus = addrs.addr("venus");
+    let random = addrs.addr("random");
 
-    // Mint a couple tokens (from the same owner)
-    let token_id1 = "grow1".to_string();
-    let token_uri1 = "https://www.merriam-webster.com/dictionary/grow1".to_string();
-
-    let token_id2 = "grow2".to_string();
-    let token_uri2 = "https://www.merriam-webster.com/dictionary/grow2".to_string();
+    // no NFTs owned
+    let balance = contract
+        .query_balance_of(deps.as_ref(), venus.to_string())
+        .unwrap();
+    assert_eq!(balance.balance, 0);
 
-    let mint_msg1 = Cw721ExecuteMsg::Mint {
-        token_id: token_id1.clone(),
-        owner: addrs.addr("demeter").to_string(),
-        token_uri: Some(token_uri1),
+    let mint_msg = Cw721ExecuteMsg::Mint {
+        token_id: "melt".to_string(),
+        owner: venus.to_string(),
+        token_uri: None,
         extension: None,
     };
-
-    let minter_info = addrs.info("minter");
     contract
-        .execute(deps.as_mut(), &mock_env(), &minter_info, mint_msg1)
+        .execute(deps.as_mut(), &mock_env(), &addrs.info("minter"), mint_msg)
         .unwrap();
-
-    let mint_msg2 = Cw721ExecuteMsg::Mint {
-        token_id: token_id2.clone(),
-        owner: addrs.addr("demeter").to_string(),
-        token_uri: Some(token_uri2),
-        extension: None,
+    let batch_mint_msg = Cw721ExecuteMsg::BatchMint {
+        tokens: ["grow", "sing"]
+            .iter()
+            .map(|token_id| MintMsg {
+                token_id: token_id.to_string(),
+                owner: venus.to_string(),
+                token_uri: None,
+                extension: None,
+            })
+            .collect(),
+    };
+    contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("minter"),
+            batch_mint_msg,
+        )
+        .unwrap();
+    let balance = contract
+        .query_balance_of(deps.as_ref(), venus.to_string())
+        .unwrap();
+    assert_eq!(balance.balance, 3);
+
+    // transfer moves balance to recipient
+    let transfer_msg = Cw721ExecuteMsg::TransferNft {
+        recipient: random.to_string(),
+        token_id: "melt".to_string(),
+    };
+    contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("venus"),
+            transfer_msg,
+        )
+        .unwrap();
+    let balance = contract
+        .query_balance_of(deps.as_ref(), venus.to_string())
+        .unwrap();
+    assert_eq!(balance.balance, 2);
+    let balance = contract
+        .query_balance_of(deps.as_ref(), random.to_string())
+        .unwrap();
+    assert_eq!(balance.balance, 1);
+
+    // send moves balance to contract
+    let target = addrs.addr("target");
+    let send_msg = Cw721ExecuteMsg::SendNft {
+        contract: target.to_string(),
+        token_id: "grow".to_string(),
+        msg: to_json_binary("yes").unwrap(),
+    };
+    contract
+        .execute(deps.as_mut(), &mock_env(), &addrs.info("venus"), send_msg)
+        .unwrap();
+    let balance = contract
+        .query_balance_of(deps.as_ref(), target.to_string())
+        .unwrap();
+    assert_eq!(balance.balance, 1);
+
+    // burn decreases balance
+    let burn_msg = Cw721ExecuteMsg::Burn {
+        token_id: "sing".to_string(),
+    };
+    contract
+        .execute(deps.as_mut(), &mock_env(), &addrs.info("venus"), burn_msg)
+        .unwrap();
+    let balance = contract
+        .query_balance_of(deps.as_ref(), venus.to_string())
+        .unwrap();
+    assert_eq!(balance.balance, 0);
+
+    // query by message
+    let query_msg = Cw721QueryMsg::BalanceOf {
+        owner: random.to_string(),
+    };
+    let balance: BalanceOfResponse = from_json(
+        contract
+            .query(deps.as_ref(), &mock_env(), query_msg)
+            .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(balance.balance, 1);
+}
+
+#[test]
+fn test_ownership_snapshots() {
+    let mut deps = mock_dependencies();
+    let contract = Cw721OnchainExtensions::default();
+    let mut addrs = MockAddrFactory::new(deps.api);
+    let creator = addrs.addr("creator");
+    let minter = addrs.addr("minter");
+    let venus = addrs.addr("venus");
+    let random = addrs.addr("random");
+
+    // snapshots are disabled by default
+    setup_contract(deps.as_mut(), &creator, &minter);
+    let err = contract
+        .query_total_supply_at_height(deps.as_ref(), mock_env().block.height)
+        .unwrap_err();
+    assert_eq!(err, StdError::generic_err("Snapshots are not enabled"));
+
+    let mut deps = mock_dependencies();
+    let msg = Cw721InstantiateMsg::<DefaultOptionalCollectionExtensionMsg> {
+        name: CONTRACT_NAME.to_string(),
+        symbol: SYMBOL.to_string(),
+        collection_info_extension: None,
+        minter: Some(minter.to_string()),
+        creator: Some(creator.to_string()),
+        withdraw_address: None,
+        enable_snapshots: Some(true),
+        allow_burned_remint: None,
+        token_history_depth: None,
+        token_id_strategy: None,
+        max_supply: None,
+        base_uri: None,
+        reveal: None,
+    };
+    contract
+        .instantiate(deps.as_mut(), &mock_env(), &addrs.info("creator"), msg)
+        .unwrap();
+
+    // mint, transfer and burn in consecutive blocks
+    let mut env = mock_env();
+    let mint_height = env.block.height;
+    let mint_msg = Cw721ExecuteMsg::Mint {
+        token_id: "melt".to_string(),
+        owner: venus.to_string(),
+        token_uri: None,
+        extension: None,
+    };
+    contract
+        .execute(deps.as_mut(), &env, &addrs.info("minter"), mint_msg)
+        .unwrap();
+    env.block.height += 1;
+    let transfer_msg = Cw721ExecuteMsg::TransferNft {
+        recipient: random.to_string(),
+        token_id: "melt".to_string(),
+    };
+    contract
+        .execute(deps.as_mut(), &env, &addrs.info("venus"), transfer_msg)
+        .unwrap();
+    env.block.height += 1;
+    let burn_msg = Cw721ExecuteMsg::Burn {
+        token_id: "melt".to_string(),
+    };
+    contract
+        .execute(deps.as_mut(), &env, &addrs.info("random"), burn_msg)
+        .unwrap();
+
+    // snapshots reflect state at the beginning of each block
+    let expected = [
+        (mint_height, None, 0, 0, 0),
+        (mint_height + 1, Some(venus.to_string()), 1, 0, 1),
+        (mint_height + 2, Some(random.to_string()), 0, 1, 1),
+        (mint_height + 3, None, 0, 0, 0),
+    ];
+    for (height, owner, venus_balance, random_balance, total_supply) in expected {
+        let res = contract
+            .query_owner_of_at_height(deps.as_ref(), "melt".to_string(), height)
+            .unwrap();
+        assert_eq!(res.owner, owner);
+        let res = contract
+            .query_balance_at_height(deps.as_ref(), venus.to_string(), height)
+            .unwrap();
+        assert_eq!(res.balance, venus_balance);
+        let res = contract
+            .query_balance_at_height(deps.as_ref(), random.to_string(), height)
+            .unwrap();
+        assert_eq!(res.balance, random_balance);
+        let res = contract
+            .query_total_supply_at_height(deps.as_ref(), height)
+            .unwrap();
+        assert_eq!(res.count, total_supply);
+    }
+
+    // query by message
+    let query_msg = Cw721QueryMsg::OwnerOfAtHeight {
+        token_id: "melt".to_string(),
+        height: mint_height + 1,
+    };
+    let res: OwnerOfAtHeightResponse =
+        from_json(contract.query(deps.as_ref(), &env, query_msg).unwrap()).unwrap();
+    assert_eq!(res.owner, Some(venus.to_string()));
+}
+
+#[test]
+fn test_start_trading_time() {
+    let mut deps = mock_dependencies();
+    let contract = Cw721OnchainExtensions::default();
+    let mut addrs = MockAddrFactory::new(deps.api);
+    let creator = addrs.addr("creator");
+    let minter = addrs.addr("minter");
+    let venus = addrs.addr("venus");
+    let random = addrs.addr("random");
+    let start_trading_time = mock_env().block.time.plus_seconds(100);
+    let msg = Cw721InstantiateMsg::<DefaultOptionalCollectionExtensionMsg> {
+        name: CONTRACT_NAME.to_string(),
+        symbol: SYMBOL.to_string(),
+        collection_info_extension: Some(CollectionExtensionMsg {
+            description: Some("description".to_string()),
+            image: Some("https://moonphases.org".to_string()),
+            explicit_content: None,
+            external_link: None,
+            start_trading_time: Some(start_trading_time),
+            royalty_info: None,
+        }),
+        minter: Some(minter.to_string()),
+        creator: Some(creator.to_string()),
+        withdraw_address: None,
+        enable_snapshots: None,
+        allow_burned_remint: None,
+        token_history_depth: None,
+        token_id_strategy: None,
+        max_supply: None,
+        base_uri: None,
+        reveal: None,
+    };
+    contract
+        .instantiate(deps.as_mut(), &mock_env(), &addrs.info("creator"), msg)
+        .unwrap();
+
+    // minting works before trading starts
+    for (token_id, owner) in [("melt", &minter), ("grow", &venus)] {
+        let mint_msg = Cw721ExecuteMsg::Mint {
+            token_id: token_id.to_string(),
+            owner: owner.to_string(),
+            token_uri: None,
+            extension: None,
+        };
+        contract
+            .execute(deps.as_mut(), &mock_env(), &addrs.info("minter"), mint_msg)
+            .unwrap();
+    }
+
+    // minter can distribute before trading starts
+    let transfer_msg = Cw721ExecuteMsg::TransferNft {
+        recipient: venus.to_string(),
+        token_id: "melt".to_string(),
+    };
+    contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("minter"),
+            transfer_msg,
+        )
+        .unwrap();
+
+    // owner cannot transfer, send or approve before trading starts
+    let expected_err = Cw721ContractError::TradingNotStarted { start_trading_time };
+    let transfer_msg = Cw721ExecuteMsg::TransferNft {
+        recipient: random.to_string(),
+        token_id: "melt".to_string(),
+    };
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("venus"),
+            transfer_msg.clone(),
+        )
+        .unwrap_err();
+    assert_eq!(err, expected_err);
+    let send_msg = Cw721ExecuteMsg::SendNft {
+        contract: random.to_string(),
+        token_id: "melt".to_string(),
+        msg: to_json_binary("You now have the melting power").unwrap(),
+    };
+    let err = contract
+        .execute(deps.as_mut(), &mock_env(), &addrs.info("venus"), send_msg)
+        .unwrap_err();
+    assert_eq!(err, expected_err);
+    let approve_msg = Cw721ExecuteMsg::Approve {
+        spender: random.to_string(),
+        token_id: "melt".to_string(),
+        expires: None,
+    };
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("venus"),
+            approve_msg,
+        )
+        .unwrap_err();
+    assert_eq!(err, expected_err);
+    let approve_all_msg = Cw721ExecuteMsg::ApproveAll {
+        operator: random.to_string(),
+        expires: None,
+    };
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("venus"),
+            approve_all_msg,
+        )
+        .unwrap_err();
+    assert_eq!(err, expected_err);
+
+    // owner can transfer once trading started
+    let mut env = mock_env();
+    env.block.time = start_trading_time;
+    contract
+        .execute(deps.as_mut(), &env, &addrs.info("venus"), transfer_msg)
+        .unwrap();
+}
+
+#[test]
+fn test_approve_revoke() {
+    let mut deps = mock_dependencies();
+    let mut addrs = MockAddrFactory::new(deps.api);
+    let creator = addrs.addr("creator");
+    let minter = addrs.addr("minter");
+    let contract = setup_contract(deps.as_mut(), &creator, &minter);
+
+    // Mint a token
+    let token_id = "grow".to_string();
+    let token_uri = "https://www.merriam-webster.com/dictionary/grow".to_string();
+
+    let mint_msg = Cw721ExecuteMsg::Mint {
+        token_id: token_id.clone(),
+        owner: addrs.addr("demeter").to_string(),
+        token_uri: Some(token_uri),
+        extension: None,
+    };
+
+    let minter_info = addrs.info("minter");
+    contract
+        .execute(deps.as_mut(), &mock_env(), &minter_info, mint_msg)
+        .unwrap();
+
+    // token owner shows in approval query
+    let res = contract
+        .query_approval(
+            deps.as_ref(),
+            &mock_env(),
+            token_id.clone(),
+            addrs.addr("demeter").to_string(),
+            false,
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        ApprovalResponse {
+            approval: Approval {
+                spender: addrs.addr("demeter"),
+                expires: Expiration::Never {}
+            }
+        }
+    );
+
+    // Give random transferring power
+    let approve_msg = Cw721ExecuteMsg::Approve {
+        spender: addrs.addr("random").to_string(),
+        token_id: token_id.clone(),
+        expires: None,
+    };
+    let owner_info = addrs.info("demeter");
+    let res = contract
+        .execute(deps.as_mut(), &mock_env(), &owner_info, approve_msg)
+        .unwrap();
+    assert_eq!(
+        res,
+        Response::new()
+            .add_attribute("action", "approve")
+            .add_attribute("sender", addrs.addr("demeter").to_string())
+            .add_attribute("spender", addrs.addr("random").to_string())
+            .add_attribute("token_id", token_id.clone())
+    );
+
+    // test approval query
+    let res = contract
+        .query_approval(
+            deps.as_ref(),
+            &mock_env(),
+            token_id.clone(),
+            addrs.addr("random").to_string(),
+            true,
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        ApprovalResponse {
+            approval: Approval {
+                spender: addrs.addr("random"),
+                expires: Expiration::Never {}
+            }
+        }
+    );
+
+    // random can now transfer
+    let random = addrs.addr("random");
+    let random_info = addrs.info("random");
+    let person = addrs.addr("person");
+    let transfer_msg = Cw721ExecuteMsg::TransferNft {
+        recipient: person.to_string(),
+        token_id: token_id.clone(),
+    };
+    contract
+        .execute(deps.as_mut(), &mock_env(), &random_info, transfer_msg)
+        .unwrap();
+
+    // Approvals are removed / cleared
+    let query_msg = Cw721QueryMsg::OwnerOf {
+        token_id: token_id.clone(),
+        include_expired: None,
+    };
+    let res: OwnerOfResponse = from_json(
+        contract
+            .query(deps.as_ref(), &mock_env(), query_msg.clone())
+            .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        res,
+        OwnerOfResponse {
+            owner: person.to_string(),
+            approvals: vec![],
+        }
+    );
+
+    // Approve, revoke, and check for empty, to test revoke
+    let approve_msg = Cw721ExecuteMsg::Approve {
+        spender: random.to_string(),
+        token_id: token_id.clone(),
+        expires: None,
+    };
+    let owner_info = addrs.info("person");
+    contract
+        .execute(deps.as_mut(), &mock_env(), &owner_info, approve_msg)
+        .unwrap();
+
+    let revoke_msg = Cw721ExecuteMsg::Revoke {
+        spender: addrs.addr("random").to_string(),
+        token_id,
+    };
+    contract
+        .execute(deps.as_mut(), &mock_env(), &owner_info, revoke_msg)
+        .unwrap();
+
+    // Approvals are now removed / cleared
+    let res: OwnerOfResponse = from_json(
+        contract
+            .query(deps.as_ref(), &mock_env(), query_msg)
+            .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        res,
+        OwnerOfResponse {
+            owner: person.to_string(),
+            approvals: vec![],
+        }
+    );
+}
+
+#[test]
+fn test_set_user() {
+    let mut deps = mock_dependencies();
+    let mut addrs = MockAddrFactory::new(deps.api);
+    let creator = addrs.addr("creator");
+    let minter = addrs.addr("minter");
+    let contract = setup_contract(deps.as_mut(), &creator, &minter);
+    let venus = addrs.addr("venus");
+    let random = addrs.addr("random");
+    let renter = addrs.addr("renter");
+
+    let mint_msg = Cw721ExecuteMsg::Mint {
+        token_id: "melt".to_string(),
+        owner: venus.to_string(),
+        token_uri: None,
+        extension: None,
+    };
+    contract
+        .execute(deps.as_mut(), &mock_env(), &addrs.info("minter"), mint_msg)
+        .unwrap();
+
+    // no user by default
+    let res = contract
+        .query_user_of(deps.as_ref(), &mock_env(), "melt".to_string())
+        .unwrap();
+    assert_eq!(
+        res,
+        UserOfResponse {
+            user: None,
+            expires: None,
+        }
+    );
+
+    // random cannot set user
+    let expires = Expiration::AtHeight(mock_env().block.height + 10);
+    let set_user_msg = Cw721ExecuteMsg::SetUser {
+        token_id: "melt".to_string(),
+        user: Some(renter.to_string()),
+        expires: Some(expires),
+    };
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("random"),
+            set_user_msg.clone(),
+        )
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::Ownership(OwnershipError::NotOwner));
+
+    // expired user is rejected
+    let expired_msg = Cw721ExecuteMsg::SetUser {
+        token_id: "melt".to_string(),
+        user: Some(renter.to_string()),
+        expires: Some(Expiration::AtHeight(mock_env().block.height)),
+    };
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("venus"),
+            expired_msg,
+        )
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::Expired {});
+
+    // owner sets user
+    let res = contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("venus"),
+            set_user_msg.clone(),
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        Response::new()
+            .add_attribute("action", "set_user")
+            .add_attribute("sender", venus.to_string())
+            .add_attribute("token_id", "melt")
+            .add_attribute("user", renter.to_string())
+    );
+    let res = contract
+        .query_user_of(deps.as_ref(), &mock_env(), "melt".to_string())
+        .unwrap();
+    assert_eq!(
+        res,
+        UserOfResponse {
+            user: Some(renter.to_string()),
+            expires: Some(expires),
+        }
+    );
+    // owner keeps custody
+    let res = contract
+        .query_owner_of(deps.as_ref(), &mock_env(), "melt".to_string(), false)
+        .unwrap();
+    assert_eq!(res.owner, venus.to_string());
+
+    // user is not returned once expired
+    let mut env = mock_env();
+    env.block.height += 10;
+    let res = contract
+        .query_user_of(deps.as_ref(), &env, "melt".to_string())
+        .unwrap();
+    assert_eq!(res.user, None);
+
+    // user can be removed
+    let remove_user_msg = Cw721ExecuteMsg::SetUser {
+        token_id: "melt".to_string(),
+        user: None,
+        expires: None,
+    };
+    contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("venus"),
+            remove_user_msg,
+        )
+        .unwrap();
+    let res = contract
+        .query_user_of(deps.as_ref(), &mock_env(), "melt".to_string())
+        .unwrap();
+    assert_eq!(res.user, None);
+
+    // user is cleared on transfer
+    contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("venus"),
+            set_user_msg,
+        )
+        .unwrap();
+    let transfer_msg = Cw721ExecuteMsg::TransferNft {
+        recipient: random.to_string(),
+        token_id: "melt".to_string(),
+    };
+    contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("venus"),
+            transfer_msg,
+        )
+        .unwrap();
+    let query_msg = Cw721QueryMsg::UserOf {
+        token_id: "melt".to_string(),
+    };
+    let res: UserOfResponse = from_json(
+        contract
+            .query(deps.as_ref(), &mock_env(), query_msg)
+            .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(res.user, None);
+}
+
+#[test]
+fn test_lock_unlock() {
+    let mut deps = mock_dependencies();
+    let mut addrs = MockAddrFactory::new(deps.api);
+    let creator = addrs.addr("creator");
+    let minter = addrs.addr("minter");
+    let contract = setup_contract(deps.as_mut(), &creator, &minter);
+    let venus = addrs.addr("venus");
+    let random = addrs.addr("random");
+    let staker = addrs.addr("staker");
+
+    for token_id in ["melt", "grow"] {
+        let mint_msg = Cw721ExecuteMsg::Mint {
+            token_id: token_id.to_string(),
+            owner: venus.to_string(),
+            token_uri: None,
+            extension: None,
+        };
+        contract
+            .execute(deps.as_mut(), &mock_env(), &addrs.info("minter"), mint_msg)
+            .unwrap();
+    }
+
+    // locker must be approved
+    let lock_msg = Cw721ExecuteMsg::Lock {
+        token_id: "melt".to_string(),
+    };
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("staker"),
+            lock_msg.clone(),
+        )
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::Ownership(OwnershipError::NotOwner));
+
+    let approve_msg = Cw721ExecuteMsg::Approve {
+        spender: staker.to_string(),
+        token_id: "melt".to_string(),
+        expires: None,
+    };
+    contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("venus"),
+            approve_msg,
+        )
+        .unwrap();
+    let res = contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("staker"),
+            lock_msg.clone(),
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        Response::new()
+            .add_attribute("action", "lock")
+            .add_attribute("sender", staker.to_string())
+            .add_attribute("token_id", "melt")
+    );
+
+    // cannot lock twice
+    let err = contract
+        .execute(deps.as_mut(), &mock_env(), &addrs.info("venus"), lock_msg)
+        .unwrap_err();
+    assert_eq!(
+        err,
+        Cw721ContractError::TokenLocked {
+            token_id: "melt".to_string()
+        }
+    );
+
+    // locked token cannot be transferred, sent or burned, even by owner
+    let locked_msgs = [
+        Cw721ExecuteMsg::TransferNft {
+            recipient: random.to_string(),
+            token_id: "melt".to_string(),
+        },
+        Cw721ExecuteMsg::SendNft {
+            contract: random.to_string(),
+            token_id: "melt".to_string(),
+            msg: to_json_binary("yes").unwrap(),
+        },
+        Cw721ExecuteMsg::Burn {
+            token_id: "melt".to_string(),
+        },
+    ];
+    for msg in locked_msgs {
+        let err = contract
+            .execute(deps.as_mut(), &mock_env(), &addrs.info("venus"), msg)
+            .unwrap_err();
+        assert_eq!(
+            err,
+            Cw721ContractError::TokenLocked {
+                token_id: "melt".to_string()
+            }
+        );
+    }
+
+    // owner keeps custody
+    let res = contract
+        .query_owner_of(deps.as_ref(), &mock_env(), "melt".to_string(), false)
+        .unwrap();
+    assert_eq!(res.owner, venus.to_string());
+    let res = contract
+        .query_lock_status(deps.as_ref(), "melt".to_string())
+        .unwrap();
+    assert_eq!(
+        res,
+        LockStatusResponse {
+            locked: true,
+            locker: Some(staker.to_string()),
+        }
+    );
+    let query_msg = Cw721QueryMsg::LocksByLocker {
+        locker: staker.to_string(),
+        start_after: None,
+        limit: None,
+    };
+    let res: TokensResponse = from_json(
+        contract
+            .query(deps.as_ref(), &mock_env(), query_msg)
+            .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(res.tokens, vec!["melt".to_string()]);
+
+    // only locker can unlock
+    let unlock_msg = Cw721ExecuteMsg::Unlock {
+        token_id: "melt".to_string(),
+    };
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("venus"),
+            unlock_msg.clone(),
+        )
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::Ownership(OwnershipError::NotOwner));
+    contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("staker"),
+            unlock_msg.clone(),
+        )
+        .unwrap();
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("staker"),
+            unlock_msg,
+        )
+        .unwrap_err();
+    assert_eq!(
+        err,
+        Cw721ContractError::TokenNotLocked {
+            token_id: "melt".to_string()
+        }
+    );
+    let res = contract
+        .query_locks_by_locker(deps.as_ref(), staker.to_string(), None, None)
+        .unwrap();
+    assert!(res.tokens.is_empty());
+
+    // unlocked token can be transferred again
+    let transfer_msg = Cw721ExecuteMsg::TransferNft {
+        recipient: random.to_string(),
+        token_id: "melt".to_string(),
+    };
+    contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("venus"),
+            transfer_msg,
+        )
+        .unwrap();
+}
+
+#[test]
+fn test_pause() {
+    let mut deps = mock_dependencies();
+    let mut addrs = MockAddrFactory::new(deps.api);
+    let creator = addrs.addr("creator");
+    let minter = addrs.addr("minter");
+    let contract = setup_contract(deps.as_mut(), &creator, &minter);
+    let venus = addrs.addr("venus");
+    let random = addrs.addr("random");
+    let pauser = addrs.addr("pauser");
+
+    // pauser is initialized with creator
+    let ownership = contract
+        .query_pauser_ownership(deps.as_ref().storage)
+        .unwrap();
+    assert_eq!(ownership.owner, Some(creator.clone()));
+
+    // only pauser or creator can pause
+    let pause_minting_msg = Cw721ExecuteMsg::Pause {
+        scopes: vec![PauseScope::Minting],
+    };
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("random"),
+            pause_minting_msg.clone(),
+        )
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::NotPauserOrCreator {});
+    let res = contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("creator"),
+            pause_minting_msg,
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        Response::new()
+            .add_attribute("action", "pause")
+            .add_attribute("sender", creator.to_string())
+            .add_attribute("scopes", "minting")
+    );
+    let mint_msg = Cw721ExecuteMsg::Mint {
+        token_id: "melt".to_string(),
+        owner: venus.to_string(),
+        token_uri: None,
+        extension: None,
+    };
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("minter"),
+            mint_msg.clone(),
+        )
+        .unwrap_err();
+    assert_eq!(
+        err,
+        Cw721ContractError::Paused {
+            scope: PauseScope::Minting
+        }
+    );
+
+    // dedicated pauser
+    let transfer_ownership_msg =
+        Cw721ExecuteMsg::UpdatePauserOwnership(Action::TransferOwnership {
+            new_owner: pauser.to_string(),
+            expiry: None,
+        });
+    contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("creator"),
+            transfer_ownership_msg,
+        )
+        .unwrap();
+    contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("pauser"),
+            Cw721ExecuteMsg::UpdatePauserOwnership(Action::AcceptOwnership),
+        )
+        .unwrap();
+    let unpause_msg = Cw721ExecuteMsg::Unpause {
+        scopes: vec![PauseScope::Minting],
+    };
+    contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("pauser"),
+            unpause_msg,
+        )
+        .unwrap();
+    contract
+        .execute(deps.as_mut(), &mock_env(), &addrs.info("minter"), mint_msg)
+        .unwrap();
+
+    // scopes are paused independently
+    let pause_msg = Cw721ExecuteMsg::Pause {
+        scopes: vec![
+            PauseScope::Transfers,
+            PauseScope::Approvals,
+            PauseScope::Metadata,
+        ],
+    };
+    contract
+        .execute(deps.as_mut(), &mock_env(), &addrs.info("pauser"), pause_msg)
+        .unwrap();
+    let query_msg = Cw721QueryMsg::PauseStatus {};
+    let status: PauseStatus = from_json(
+        contract
+            .query(deps.as_ref(), &mock_env(), query_msg)
+            .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        status,
+        PauseStatus {
+            minting: false,
+            transfers: true,
+            approvals: true,
+            metadata: true,
+        }
+    );
+    let transfer_msg = Cw721ExecuteMsg::TransferNft {
+        recipient: random.to_string(),
+        token_id: "melt".to_string(),
+    };
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("venus"),
+            transfer_msg,
+        )
+        .unwrap_err();
+    assert_eq!(
+        err,
+        Cw721ContractError::Paused {
+            scope: PauseScope::Transfers
+        }
+    );
+    let approve_msg = Cw721ExecuteMsg::Approve {
+        spender: random.to_string(),
+        token_id: "melt".to_string(),
+        expires: None,
+    };
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("venus"),
+            approve_msg,
+        )
+        .unwrap_err();
+    assert_eq!(
+        err,
+        Cw721ContractError::Paused {
+            scope: PauseScope::Approvals
+        }
+    );
+    let update_msg = Cw721ExecuteMsg::UpdateNftInfo {
+        token_id: "melt".to_string(),
+        token_uri: Some("ipfs://to.the.moon".to_string()),
+        extension: None,
+    };
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("creator"),
+            update_msg,
+        )
+        .unwrap_err();
+    assert_eq!(
+        err,
+        Cw721ContractError::Paused {
+            scope: PauseScope::Metadata
+        }
+    );
+    // minting is still possible
+    let mint_msg = Cw721ExecuteMsg::Mint {
+        token_id: "grow".to_string(),
+        owner: venus.to_string(),
+        token_uri: None,
+        extension: None,
+    };
+    contract
+        .execute(deps.as_mut(), &mock_env(), &addrs.info("minter"), mint_msg)
+        .unwrap();
+}
+
+#[test]
+fn test_operator_filter() {
+    let mut deps = mock_dependencies();
+    let mut addrs = MockAddrFactory::new(deps.api);
+    let creator = addrs.addr("creator");
+    let minter = addrs.addr("minter");
+    let contract = setup_contract(deps.as_mut(), &creator, &minter);
+    let venus = addrs.addr("venus");
+    let random = addrs.addr("random");
+    let market = addrs.addr("market");
+    let other_market = addrs.addr("other_market");
+
+    for token_id in ["melt", "grow"] {
+        let mint_msg = Cw721ExecuteMsg::Mint {
+            token_id: token_id.to_string(),
+            owner: venus.to_string(),
+            token_uri: None,
+            extension: None,
+        };
+        contract
+            .execute(deps.as_mut(), &mock_env(), &addrs.info("minter"), mint_msg)
+            .unwrap();
+    }
+    // approval granted before market is blocked
+    let approve_msg = Cw721ExecuteMsg::Approve {
+        spender: market.to_string(),
+        token_id: "grow".to_string(),
+        expires: None,
+    };
+    contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("venus"),
+            approve_msg,
+        )
+        .unwrap();
+
+    // only creator can manage operator filter
+    let set_mode_msg = Cw721ExecuteMsg::SetOperatorFilterMode {
+        mode: Some(OperatorFilterMode::Blocklist),
+    };
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("random"),
+            set_mode_msg.clone(),
+        )
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::NotCreator {});
+    contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("creator"),
+            set_mode_msg,
+        )
+        .unwrap();
+    let add_msg = Cw721ExecuteMsg::AddFilteredOperators {
+        operators: vec![market.to_string()],
+    };
+    contract
+        .execute(deps.as_mut(), &mock_env(), &addrs.info("creator"), add_msg)
+        .unwrap();
+
+    // blocked operator can neither be approved, transfer nor receive NFTs
+    let blocked_err = Cw721ContractError::OperatorNotAllowed {
+        operator: market.to_string(),
+    };
+    let blocked_msgs = [
+        (
+            "venus",
+            Cw721ExecuteMsg::Approve {
+                spender: market.to_string(),
+                token_id: "melt".to_string(),
+                expires: None,
+            },
+        ),
+        (
+            "venus",
+            Cw721ExecuteMsg::ApproveAll {
+                operator: market.to_string(),
+                expires: None,
+            },
+        ),
+        (
+            "venus",
+            Cw721ExecuteMsg::SendNft {
+                contract: market.to_string(),
+                token_id: "melt".to_string(),
+                msg: to_json_binary("yes").unwrap(),
+            },
+        ),
+        (
+            "market",
+            Cw721ExecuteMsg::TransferNft {
+                recipient: random.to_string(),
+                token_id: "grow".to_string(),
+            },
+        ),
+    ];
+    for (sender, msg) in blocked_msgs {
+        let err = contract
+            .execute(deps.as_mut(), &mock_env(), &addrs.info(sender), msg)
+            .unwrap_err();
+        assert_eq!(err, blocked_err);
+    }
+    let res = contract
+        .query_is_operator_allowed(deps.as_ref(), market.to_string())
+        .unwrap();
+    assert!(!res.allowed);
+    let res = contract
+        .query_is_operator_allowed(deps.as_ref(), other_market.to_string())
+        .unwrap();
+    assert!(res.allowed);
+
+    // in allowlist mode, only listed operators are allowed
+    let set_mode_msg = Cw721ExecuteMsg::SetOperatorFilterMode {
+        mode: Some(OperatorFilterMode::Allowlist),
+    };
+    contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("creator"),
+            set_mode_msg,
+        )
+        .unwrap();
+    let approve_msg = Cw721ExecuteMsg::Approve {
+        spender: other_market.to_string(),
+        token_id: "melt".to_string(),
+        expires: None,
+    };
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("venus"),
+            approve_msg,
+        )
+        .unwrap_err();
+    assert_eq!(
+        err,
+        Cw721ContractError::OperatorNotAllowed {
+            operator: other_market.to_string(),
+        }
+    );
+    let transfer_msg = Cw721ExecuteMsg::TransferNft {
+        recipient: random.to_string(),
+        token_id: "grow".to_string(),
+    };
+    contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("market"),
+            transfer_msg,
+        )
+        .unwrap();
+
+    let query_msg = Cw721QueryMsg::OperatorFilter {
+        start_after: None,
+        limit: None,
+    };
+    let res: OperatorFilterResponse = from_json(
+        contract
+            .query(deps.as_ref(), &mock_env(), query_msg)
+            .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        res,
+        OperatorFilterResponse {
+            mode: Some(OperatorFilterMode::Allowlist),
+            operators: vec![market.to_string()],
+        }
+    );
+
+    // owner is never filtered
+    let transfer_msg = Cw721ExecuteMsg::TransferNft {
+        recipient: random.to_string(),
+        token_id: "melt".to_string(),
+    };
+    contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("venus"),
+            transfer_msg,
+        )
+        .unwrap();
+
+    // removing operators and disabling filter
+    let remove_msg = Cw721ExecuteMsg::RemoveFilteredOperators {
+        operators: vec![market.to_string()],
+    };
+    contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("creator"),
+            remove_msg,
+        )
+        .unwrap();
+    let res = contract
+        .query_is_operator_allowed(deps.as_ref(), market.to_string())
+        .unwrap();
+    assert!(!res.allowed);
+    let disable_msg = Cw721ExecuteMsg::SetOperatorFilterMode { mode: None };
+    contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("creator"),
+            disable_msg,
+        )
+        .unwrap();
+    let res = contract
+        .query_operator_filter(deps.as_ref(), None, None)
+        .unwrap();
+    assert_eq!(res.mode, None);
+    assert!(res.operators.is_empty());
+    let res = contract
+        .query_is_operator_allowed(deps.as_ref(), other_market.to_string())
+        .unwrap();
+    assert!(res.allowed);
+}
+
+#[test]
+fn test_freeze_metadata() {
+    let mut deps = mock_dependencies();
+    let mut addrs = MockAddrFactory::new(deps.api);
+    let creator = addrs.addr("creator");
+    let minter = addrs.addr("minter");
+    let contract = setup_contract(deps.as_mut(), &creator, &minter);
+    let venus = addrs.addr("venus");
+
+    for token_id in ["melt", "grow"] {
+        let mint_msg = Cw721ExecuteMsg::Mint {
+            token_id: token_id.to_string(),
+            owner: venus.to_string(),
+            token_uri: None,
+            extension: None,
+        };
+        contract
+            .execute(deps.as_mut(), &mock_env(), &addrs.info("minter"), mint_msg)
+            .unwrap();
+    }
+    let update_nft_info_msg = |token_id: &str| Cw721ExecuteMsg::UpdateNftInfo {
+        token_id: token_id.to_string(),
+        token_uri: Some("ipfs://to.the.moon".to_string()),
+        extension: None,
+    };
+    let update_collection_info_msg = Cw721ExecuteMsg::UpdateCollectionInfo {
+        collection_info: CollectionInfoMsg {
+            name: Some("new name".to_string()),
+            symbol: None,
+            extension: None,
+        },
+    };
+
+    // nothing frozen by default
+    let status = contract
+        .query_freeze_status(deps.as_ref(), Some("melt".to_string()))
+        .unwrap();
+    assert_eq!(
+        status,
+        FreezeStatusResponse {
+            collection_info_frozen: false,
+            all_nft_info_frozen: false,
+            base_uri_frozen: false,
+            nft_info_frozen: Some(false),
+        }
+    );
+
+    // only creator can freeze
+    let freeze_msg = Cw721ExecuteMsg::FreezeNftInfo {
+        token_id: "melt".to_string(),
+    };
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("minter"),
+            freeze_msg.clone(),
+        )
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::NotCreator {});
+    // unknown token can't be frozen
+    contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("creator"),
+            Cw721ExecuteMsg::FreezeNftInfo {
+                token_id: "unknown".to_string(),
+            },
+        )
+        .unwrap_err();
+    let res = contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("creator"),
+            freeze_msg,
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        Response::new()
+            .add_attribute("action", "freeze_nft_info")
+            .add_attribute("sender", creator.to_string())
+            .add_attribute("token_id", "melt")
+    );
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("creator"),
+            update_nft_info_msg("melt"),
+        )
+        .unwrap_err();
+    assert_eq!(
+        err,
+        Cw721ContractError::NftInfoFrozen {
+            token_id: "melt".to_string()
+        }
+    );
+    // other nft info can still be updated
+    contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("creator"),
+            update_nft_info_msg("grow"),
+        )
+        .unwrap();
+
+    // freeze all nft infos
+    contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("creator"),
+            Cw721ExecuteMsg::FreezeAllNftInfo {},
+        )
+        .unwrap();
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("creator"),
+            update_nft_info_msg("grow"),
+        )
+        .unwrap_err();
+    assert_eq!(
+        err,
+        Cw721ContractError::NftInfoFrozen {
+            token_id: "grow".to_string()
+        }
+    );
+
+    // collection info can be updated until frozen
+    contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("creator"),
+            update_collection_info_msg.clone(),
+        )
+        .unwrap();
+    contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("creator"),
+            Cw721ExecuteMsg::FreezeCollectionInfo {},
+        )
+        .unwrap();
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("creator"),
+            update_collection_info_msg,
+        )
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::CollectionInfoFrozen {});
+
+    let status = contract
+        .query_freeze_status(deps.as_ref(), Some("grow".to_string()))
+        .unwrap();
+    assert_eq!(
+        status,
+        FreezeStatusResponse {
+            collection_info_frozen: true,
+            all_nft_info_frozen: true,
+            base_uri_frozen: false,
+            nft_info_frozen: Some(true),
+        }
+    );
+    let status = contract.query_freeze_status(deps.as_ref(), None).unwrap();
+    assert_eq!(status.nft_info_frozen, None);
+}
+
+#[test]
+fn test_delayed_reveal() {
+    let mut deps = mock_dependencies();
+    let mut addrs = MockAddrFactory::new(deps.api);
+    let creator = addrs.addr("creator");
+    let minter = addrs.addr("minter");
+    let contract = setup_contract(deps.as_mut(), &creator, &minter);
+    let venus = addrs.addr("venus");
+
+    let base_uri = "ipfs://final/";
+    let salt = "s3cr3t";
+    let commitment = reveal_commitment(base_uri, salt);
+
+    // reveal without commitment fails
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("creator"),
+            Cw721ExecuteMsg::Reveal {
+                base_uri: base_uri.to_string(),
+                salt: salt.to_string(),
+            },
+        )
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::RevealNotCommitted {});
+
+    // only creator can commit
+    let commit_msg = Cw721ExecuteMsg::CommitReveal {
+        commitment: commitment.clone(),
+        placeholder_uri: "ipfs://placeholder.json".to_string(),
+    };
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("minter"),
+            commit_msg.clone(),
+        )
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::NotCreator {});
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("creator"),
+            Cw721ExecuteMsg::CommitReveal {
+                commitment: HexBinary::from(b"not a hash"),
+                placeholder_uri: "ipfs://placeholder.json".to_string(),
+            },
+        )
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::InvalidRevealCommitment {});
+    contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("creator"),
+            commit_msg.clone(),
+        )
+        .unwrap();
+
+    // tokens return placeholder uri until revealed, unless token uri is overridden
+    let mint_msg = Cw721ExecuteMsg::Mint {
+        token_id: "1".to_string(),
+        owner: venus.to_string(),
+        token_uri: None,
+        extension: None,
+    };
+    contract
+        .execute(deps.as_mut(), &mock_env(), &addrs.info("minter"), mint_msg)
+        .unwrap();
+    let nft_info = contract
+        .query_nft_info(deps.as_ref().storage, "1".to_string())
+        .unwrap();
+    assert_eq!(
+        nft_info.token_uri,
+        Some("ipfs://placeholder.json".to_string())
+    );
+    let mint_msg = Cw721ExecuteMsg::Mint {
+        token_id: "2".to_string(),
+        owner: venus.to_string(),
+        token_uri: Some("ipfs://override/2.json".to_string()),
+        extension: None,
+    };
+    contract
+        .execute(deps.as_mut(), &mock_env(), &addrs.info("minter"), mint_msg)
+        .unwrap();
+    let nft_info = contract
+        .query_nft_info(deps.as_ref().storage, "2".to_string())
+        .unwrap();
+    assert_eq!(
+        nft_info.token_uri,
+        Some("ipfs://override/2.json".to_string())
+    );
+
+    // commitment can't be changed after minting
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("creator"),
+            commit_msg.clone(),
+        )
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::RevealCommitAfterMint {});
+
+    // base uri and salt must match commitment
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("creator"),
+            Cw721ExecuteMsg::Reveal {
+                base_uri: base_uri.to_string(),
+                salt: "wrong".to_string(),
+            },
+        )
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::InvalidRevealPreimage {});
+    // moving bytes between base uri and salt does not match commitment
+    assert_ne!(reveal_commitment("ipfs://final", "/s3cr3t"), commitment);
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("creator"),
+            Cw721ExecuteMsg::Reveal {
+                base_uri: "ipfs://final/s3".to_string(),
+                salt: "cr3t".to_string(),
+            },
+        )
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::InvalidRevealPreimage {});
+    let status = contract.query_reveal_status(deps.as_ref()).unwrap();
+    assert_eq!(
+        status,
+        RevealStatusResponse {
+            commitment: Some(commitment.clone()),
+            placeholder_uri: Some("ipfs://placeholder.json".to_string()),
+            revealed: false,
+            base_uri: None,
+        }
+    );
+
+    let reveal_msg = Cw721ExecuteMsg::Reveal {
+        base_uri: base_uri.to_string(),
+        salt: salt.to_string(),
+    };
+    let res = contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("creator"),
+            reveal_msg.clone(),
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        Response::new()
+            .add_attribute("action", "reveal")
+            .add_attribute("sender", creator.to_string())
+            .add_attribute("base_uri", base_uri)
+    );
+    let nft_info = contract
+        .query_all_nft_info(deps.as_ref(), &mock_env(), "1".to_string(), false)
+        .unwrap();
+    assert_eq!(nft_info.info.token_uri, Some("ipfs://final/1".to_string()));
+    let status = contract.query_reveal_status(deps.as_ref()).unwrap();
+    assert!(status.revealed);
+    assert_eq!(status.base_uri, Some(base_uri.to_string()));
+    // overridden token uri is kept after reveal
+    let nft_info = contract
+        .query_nft_info(deps.as_ref().storage, "2".to_string())
+        .unwrap();
+    assert_eq!(
+        nft_info.token_uri,
+        Some("ipfs://override/2.json".to_string())
+    );
+    // revealed token uri keeps the configured suffix
+    contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("creator"),
+            Cw721ExecuteMsg::SetBaseUri {
+                base_uri: Some(BaseUri {
+                    uri: "ipfs://cid/".to_string(),
+                    suffix: Some(".json".to_string()),
+                }),
+            },
+        )
+        .unwrap();
+    let nft_info = contract
+        .query_nft_info(deps.as_ref().storage, "1".to_string())
+        .unwrap();
+    assert_eq!(nft_info.token_uri, Some("ipfs://final/1.json".to_string()));
+
+    // reveal is one-time only
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("creator"),
+            reveal_msg,
+        )
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::AlreadyRevealed {});
+
+    // commitment can't be made once any NFT is minted, even if all are burned
+    let mut deps = mock_dependencies();
+    let contract = setup_contract(deps.as_mut(), &creator, &minter);
+    let mint_msg = Cw721ExecuteMsg::Mint {
+        token_id: "1".to_string(),
+        owner: venus.to_string(),
+        token_uri: None,
+        extension: None,
+    };
+    contract
+        .execute(deps.as_mut(), &mock_env(), &addrs.info("minter"), mint_msg)
+        .unwrap();
+    let burn_msg = Cw721ExecuteMsg::Burn {
+        token_id: "1".to_string(),
+    };
+    contract
+        .execute(deps.as_mut(), &mock_env(), &addrs.info("venus"), burn_msg)
+        .unwrap();
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("creator"),
+            commit_msg,
+        )
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::RevealCommitAfterMint {});
+}
+
+#[test]
+fn test_base_uri() {
+    let mut deps = mock_dependencies();
+    let mut addrs = MockAddrFactory::new(deps.api);
+    let creator = addrs.addr("creator");
+    let minter = addrs.addr("minter");
+    let contract = setup_contract(deps.as_mut(), &creator, &minter);
+    let venus = addrs.addr("venus");
+
+    let base_uri = BaseUri {
+        uri: "ipfs://cid/".to_string(),
+        suffix: Some(".json".to_string()),
+    };
+    let set_base_uri_msg = Cw721ExecuteMsg::SetBaseUri {
+        base_uri: Some(base_uri.clone()),
+    };
+    // only creator can set base uri
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("minter"),
+            set_base_uri_msg.clone(),
+        )
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::NotCreator {});
+    contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("creator"),
+            Cw721ExecuteMsg::SetBaseUri {
+                base_uri: Some(BaseUri {
+                    uri: "not a url".to_string(),
+                    suffix: None,
+                }),
+            },
+        )
+        .unwrap_err();
+    let res = contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("creator"),
+            set_base_uri_msg.clone(),
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        Response::new()
+            .add_attribute("action", "set_base_uri")
+            .add_attribute("sender", creator.to_string())
+            .add_attribute("base_uri", "ipfs://cid/{token_id}.json")
+    );
+    assert_eq!(
+        contract.query_base_uri(deps.as_ref().storage).unwrap(),
+        Some(base_uri)
+    );
+
+    // token uri is derived from base uri, unless NFT has its own token uri
+    for (token_id, token_uri) in [("1", None), ("2", Some("ipfs://other/2.json"))] {
+        let mint_msg = Cw721ExecuteMsg::Mint {
+            token_id: token_id.to_string(),
+            owner: venus.to_string(),
+            token_uri: token_uri.map(str::to_string),
+            extension: None,
+        };
+        contract
+            .execute(deps.as_mut(), &mock_env(), &addrs.info("minter"), mint_msg)
+            .unwrap();
+    }
+    let nft_info = contract
+        .query_nft_info(deps.as_ref().storage, "1".to_string())
+        .unwrap();
+    assert_eq!(nft_info.token_uri, Some("ipfs://cid/1.json".to_string()));
+    let nft_info = contract
+        .query_all_nft_info(deps.as_ref(), &mock_env(), "2".to_string(), false)
+        .unwrap();
+    assert_eq!(
+        nft_info.info.token_uri,
+        Some("ipfs://other/2.json".to_string())
+    );
+    // removing token uri falls back to base uri
+    contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("creator"),
+            Cw721ExecuteMsg::UpdateNftInfo {
+                token_id: "2".to_string(),
+                token_uri: Some("".to_string()),
+                extension: None,
+            },
+        )
+        .unwrap();
+    let nft_info = contract
+        .query_nft_info(deps.as_ref().storage, "2".to_string())
+        .unwrap();
+    assert_eq!(nft_info.token_uri, Some("ipfs://cid/2.json".to_string()));
+
+    // frozen NFT keeps its token uri, when base uri changes
+    contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("creator"),
+            Cw721ExecuteMsg::FreezeNftInfo {
+                token_id: "1".to_string(),
+            },
+        )
+        .unwrap();
+    contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("creator"),
+            Cw721ExecuteMsg::SetBaseUri {
+                base_uri: Some(BaseUri {
+                    uri: "ipfs://new-cid/".to_string(),
+                    suffix: None,
+                }),
+            },
+        )
+        .unwrap();
+    let nft_info = contract
+        .query_nft_info(deps.as_ref().storage, "1".to_string())
+        .unwrap();
+    assert_eq!(nft_info.token_uri, Some("ipfs://cid/1.json".to_string()));
+    let nft_info = contract
+        .query_nft_info(deps.as_ref().storage, "2".to_string())
+        .unwrap();
+    assert_eq!(nft_info.token_uri, Some("ipfs://new-cid/2".to_string()));
+
+    // freeze base uri
+    contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("creator"),
+            Cw721ExecuteMsg::FreezeBaseUri {},
+        )
+        .unwrap();
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("creator"),
+            Cw721ExecuteMsg::SetBaseUri { base_uri: None },
+        )
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::BaseUriFrozen {});
+    let status = contract.query_freeze_status(deps.as_ref(), None).unwrap();
+    assert!(status.base_uri_frozen);
+}
+
+#[test]
+fn test_max_supply() {
+    let contract = Cw721OnchainExtensions::default();
+    for include_burned in [false, true] {
+        let mut deps = mock_dependencies();
+        let mut addrs = MockAddrFactory::new(deps.api);
+        let creator = addrs.addr("creator");
+        let minter = addrs.addr("minter");
+        let venus = addrs.addr("venus");
+        let max_supply = MaxSupply {
+            limit: 2,
+            include_burned,
+        };
+        let msg = Cw721InstantiateMsg::<DefaultOptionalCollectionExtensionMsg> {
+            name: CONTRACT_NAME.to_string(),
+            symbol: SYMBOL.to_string(),
+            collection_info_extension: None,
+            minter: Some(minter.to_string()),
+            creator: Some(creator.to_string()),
+            withdraw_address: None,
+            enable_snapshots: None,
+            allow_burned_remint: None,
+            token_history_depth: None,
+            token_id_strategy: None,
+            max_supply: Some(max_supply.clone()),
+            base_uri: None,
+            reveal: None,
+        };
+        contract
+            .instantiate(deps.as_mut(), &mock_env(), &addrs.info("creator"), msg)
+            .unwrap();
+        let mint_msg = |token_id: &str| Cw721ExecuteMsg::Mint {
+            token_id: token_id.to_string(),
+            owner: venus.to_string(),
+            token_uri: None,
+            extension: None,
+        };
+        contract
+            .execute(
+                deps.as_mut(),
+                &mock_env(),
+                &addrs.info("minter"),
+                mint_msg("1"),
+            )
+            .unwrap();
+        // batch mint exceeding max supply fails as a whole
+        let batch_mint_msg = Cw721ExecuteMsg::BatchMint {
+            tokens: ["2", "3"]
+                .map(|token_id| MintMsg {
+                    token_id: token_id.to_string(),
+                    owner: venus.to_string(),
+                    token_uri: None,
+                    extension: None,
+                })
+                .to_vec(),
+        };
+        let err = contract
+            .execute(
+                deps.as_mut(),
+                &mock_env(),
+                &addrs.info("minter"),
+                batch_mint_msg,
+            )
+            .unwrap_err();
+        assert_eq!(err, Cw721ContractError::MaxSupplyExceeded { max_supply: 2 });
+        contract
+            .execute(
+                deps.as_mut(),
+                &mock_env(),
+                &addrs.info("minter"),
+                mint_msg("2"),
+            )
+            .unwrap();
+        let err = contract
+            .execute(
+                deps.as_mut(),
+                &mock_env(),
+                &addrs.info("minter"),
+                mint_msg("3"),
+            )
+            .unwrap_err();
+        assert_eq!(err, Cw721ContractError::MaxSupplyExceeded { max_supply: 2 });
+
+        // burning frees up supply, unless burned NFTs are counted
+        contract
+            .execute(
+                deps.as_mut(),
+                &mock_env(),
+                &addrs.info("venus"),
+                Cw721ExecuteMsg::Burn {
+                    token_id: "1".to_string(),
+                },
+            )
+            .unwrap();
+        let res = contract.execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("minter"),
+            mint_msg("3"),
+        );
+        if include_burned {
+            assert_eq!(
+                res.unwrap_err(),
+                Cw721ContractError::MaxSupplyExceeded { max_supply: 2 }
+            );
+        } else {
+            res.unwrap();
+        }
+
+        let info = contract
+            .query_collection_info_and_extension(deps.as_ref())
+            .unwrap();
+        assert_eq!(info.max_supply, Some(max_supply));
+        assert!(!info.supply_sealed);
+    }
+
+    // sealing permanently disables minting
+    let mut deps = mock_dependencies();
+    let mut addrs = MockAddrFactory::new(deps.api);
+    let creator = addrs.addr("creator");
+    let minter = addrs.addr("minter");
+    let contract = setup_contract(deps.as_mut(), &creator, &minter);
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("minter"),
+            Cw721ExecuteMsg::SealSupply {},
+        )
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::NotCreator {});
+    let res = contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("creator"),
+            Cw721ExecuteMsg::SealSupply {},
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        Response::new()
+            .add_attribute("action", "seal_supply")
+            .add_attribute("sender", creator.to_string())
+    );
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("minter"),
+            Cw721ExecuteMsg::Mint {
+                token_id: "1".to_string(),
+                owner: minter.to_string(),
+                token_uri: None,
+                extension: None,
+            },
+        )
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::SupplySealed {});
+    let info = contract
+        .query_collection_info_and_extension(deps.as_ref())
+        .unwrap();
+    assert_eq!(info.max_supply, None);
+    assert!(info.supply_sealed);
+}
+
+#[test]
+fn test_burned_tokens() {
+    let contract = Cw721OnchainExtensions::default();
+    for allow_burned_remint in [None, Some(true)] {
+        let mut deps = mock_dependencies();
+        let mut addrs = MockAddrFactory::new(deps.api);
+        let creator = addrs.addr("creator");
+        let minter = addrs.addr("minter");
+        let venus = addrs.addr("venus");
+        let msg = Cw721InstantiateMsg::<DefaultOptionalCollectionExtensionMsg> {
+            name: CONTRACT_NAME.to_string(),
+            symbol: SYMBOL.to_string(),
+            collection_info_extension: None,
+            minter: Some(minter.to_string()),
+            creator: Some(creator.to_string()),
+            withdraw_address: None,
+            enable_snapshots: None,
+            allow_burned_remint,
+            token_history_depth: None,
+            token_id_strategy: None,
+            max_supply: None,
+            base_uri: None,
+            reveal: None,
+        };
+        contract
+            .instantiate(deps.as_mut(), &mock_env(), &addrs.info("creator"), msg)
+            .unwrap();
+        let mint_msg = |token_id: &str| Cw721ExecuteMsg::Mint {
+            token_id: token_id.to_string(),
+            owner: venus.to_string(),
+            token_uri: None,
+            extension: None,
+        };
+        for token_id in ["1", "2"] {
+            contract
+                .execute(
+                    deps.as_mut(),
+                    &mock_env(),
+                    &addrs.info("minter"),
+                    mint_msg(token_id),
+                )
+                .unwrap();
+        }
+        let res = contract
+            .query_is_burned(deps.as_ref(), "1".to_string())
+            .unwrap();
+        assert_eq!(
+            res,
+            IsBurnedResponse {
+                burned: false,
+                burned_by: None,
+                burned_at: None,
+            }
+        );
+
+        contract
+            .execute(
+                deps.as_mut(),
+                &mock_env(),
+                &addrs.info("minter"),
+                Cw721ExecuteMsg::BatchBurn {
+                    token_ids: vec!["1".to_string(), "2".to_string()],
+                },
+            )
+            .unwrap_err();
+        let env = mock_env();
+        contract
+            .execute(
+                deps.as_mut(),
+                &env,
+                &addrs.info("venus"),
+                Cw721ExecuteMsg::BatchBurn {
+                    token_ids: vec!["1".to_string(), "2".to_string()],
+                },
+            )
+            .unwrap();
+        let res = contract
+            .query_is_burned(deps.as_ref(), "1".to_string())
+            .unwrap();
+        assert_eq!(
+            res,
+            IsBurnedResponse {
+                burned: true,
+                burned_by: Some(venus.to_string()),
+                burned_at: Some(env.block.time),
+            }
+        );
+        let res = contract
+            .query_burned_tokens(deps.as_ref(), Some("1".to_string()), None)
+            .unwrap();
+        assert_eq!(
+            res,
+            BurnedTokensResponse {
+                tokens: vec![BurnedToken {
+                    token_id: "2".to_string(),
+                    burned_by: venus.to_string(),
+                    burned_at: env.block.time,
+                }],
+            }
+        );
+
+        // by default burned token ids can't be minted again
+        let res = contract.execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("minter"),
+            mint_msg("1"),
+        );
+        match allow_burned_remint {
+            None => {
+                assert_eq!(
+                    res.unwrap_err(),
+                    Cw721ContractError::TokenBurned {
+                        token_id: "1".to_string()
+                    }
+                );
+            }
+            Some(_) => {
+                res.unwrap();
+                // tombstone is removed on re-mint
+                let res = contract
+                    .query_is_burned(deps.as_ref(), "1".to_string())
+                    .unwrap();
+                assert!(!res.burned);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_token_id_strategy() {
+    let mut deps = mock_dependencies();
+    let contract = Cw721OnchainExtensions::default();
+    let mut addrs = MockAddrFactory::new(deps.api);
+    let creator = addrs.addr("creator");
+    let minter = addrs.addr("minter");
+    let venus = addrs.addr("venus");
+
+    let msg = Cw721InstantiateMsg::<DefaultOptionalCollectionExtensionMsg> {
+        name: CONTRACT_NAME.to_string(),
+        symbol: SYMBOL.to_string(),
+        collection_info_extension: None,
+        minter: Some(minter.to_string()),
+        creator: Some(creator.to_string()),
+        withdraw_address: None,
+        enable_snapshots: None,
+        allow_burned_remint: None,
+        token_history_depth: None,
+        token_id_strategy: Some(TokenIdStrategy::Sequential {
+            offset: 1,
+            padding: Some(3),
+        }),
+        max_supply: None,
+        base_uri: None,
+        reveal: None,
+    };
+    contract
+        .instantiate(deps.as_mut(), &mock_env(), &addrs.info("creator"), msg)
+        .unwrap();
+    let mint_msg = |token_id: &str| Cw721ExecuteMsg::Mint {
+        token_id: token_id.to_string(),
+        owner: venus.to_string(),
+        token_uri: None,
+        extension: None,
+    };
+
+    // empty token id is generated
+    let res = contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("minter"),
+            mint_msg(""),
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        Response::new()
+            .set_data(
+                to_json_binary(&MintResponse {
+                    token_id: "001".to_string()
+                })
+                .unwrap()
+            )
+            .add_attribute("action", "mint")
+            .add_attribute("minter", minter.to_string())
+            .add_attribute("owner", venus.to_string())
+            .add_attribute("token_id", "001")
+    );
+
+    // explicit token ids are still possible, and are skipped by generation
+    contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("minter"),
+            mint_msg("002"),
+        )
+        .unwrap();
+    let batch_mint_msg = Cw721ExecuteMsg::BatchMint {
+        tokens: ["", ""]
+            .map(|token_id| MintMsg {
+                token_id: token_id.to_string(),
+                owner: venus.to_string(),
+                token_uri: None,
+                extension: None,
+            })
+            .to_vec(),
+    };
+    let res = contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("minter"),
+            batch_mint_msg,
+        )
+        .unwrap();
+    let data: BatchMintResponse = from_json(res.data.unwrap()).unwrap();
+    assert_eq!(data.token_ids, vec!["003", "004"]);
+    let tokens = contract
+        .query_all_tokens(deps.as_ref(), &mock_env(), None, None)
+        .unwrap();
+    assert_eq!(tokens.tokens, vec!["001", "002", "003", "004"]);
+
+    // without strategy, empty token id is minted as is
+    let mut deps = mock_dependencies();
+    let contract = setup_contract(deps.as_mut(), &creator, &minter);
+    let res = contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("minter"),
+            mint_msg(""),
+        )
+        .unwrap();
+    let data: MintResponse = from_json(res.data.unwrap()).unwrap();
+    assert_eq!(data.token_id, "");
+    let tokens = contract
+        .query_all_tokens(deps.as_ref(), &mock_env(), None, None)
+        .unwrap();
+    assert_eq!(tokens.tokens, vec![""]);
+}
+
+#[test]
+fn test_provenance() {
+    let mut deps = mock_dependencies();
+    let mut addrs = MockAddrFactory::new(deps.api);
+    let creator = addrs.addr("creator");
+    let minter = addrs.addr("minter");
+    let contract = setup_contract(deps.as_mut(), &creator, &minter);
+    let venus = addrs.addr("venus");
+    let random = addrs.addr("random");
+
+    let mint_env = mock_env();
+    let mint_msg = Cw721ExecuteMsg::Mint {
+        token_id: "melt".to_string(),
+        owner: venus.to_string(),
+        token_uri: None,
+        extension: None,
+    };
+    contract
+        .execute(deps.as_mut(), &mint_env, &addrs.info("minter"), mint_msg)
+        .unwrap();
+    let res = contract
+        .query_all_nft_info(deps.as_ref(), &mint_env, "melt".to_string(), false)
+        .unwrap();
+    assert_eq!(
+        res.provenance,
+        NftProvenance {
+            minted_by: Some(minter.clone()),
+            minted_at: Some(mint_env.block.time),
+            last_transferred_at: None,
+        }
+    );
+
+    // transfer updates last transferred at, but keeps mint provenance
+    let mut transfer_env = mock_env();
+    transfer_env.block.time = transfer_env.block.time.plus_days(1);
+    contract
+        .execute(
+            deps.as_mut(),
+            &transfer_env,
+            &addrs.info("venus"),
+            Cw721ExecuteMsg::TransferNft {
+                recipient: random.to_string(),
+                token_id: "melt".to_string(),
+            },
+        )
+        .unwrap();
+    let res = contract
+        .query_all_nft_info(deps.as_ref(), &transfer_env, "melt".to_string(), false)
+        .unwrap();
+    assert_eq!(
+        res.provenance,
+        NftProvenance {
+            minted_by: Some(minter),
+            minted_at: Some(mint_env.block.time),
+            last_transferred_at: Some(transfer_env.block.time),
+        }
+    );
+
+    // send as well
+    let mut send_env = mock_env();
+    send_env.block.time = send_env.block.time.plus_days(2);
+    contract
+        .execute(
+            deps.as_mut(),
+            &send_env,
+            &addrs.info("random"),
+            Cw721ExecuteMsg::SendNft {
+                contract: venus.to_string(),
+                token_id: "melt".to_string(),
+                msg: to_json_binary("hello").unwrap(),
+            },
+        )
+        .unwrap();
+    let res = contract
+        .query_all_nft_info(deps.as_ref(), &send_env, "melt".to_string(), false)
+        .unwrap();
+    assert_eq!(
+        res.provenance.last_transferred_at,
+        Some(send_env.block.time)
+    );
+}
+
+#[test]
+fn test_token_history() {
+    let contract = Cw721OnchainExtensions::default();
+    let mut deps = mock_dependencies();
+    let mut addrs = MockAddrFactory::new(deps.api);
+    let creator = addrs.addr("creator");
+    let minter = addrs.addr("minter");
+    let venus = addrs.addr("venus");
+    let random = addrs.addr("random");
+
+    // token history is disabled by default
+    setup_contract(deps.as_mut(), &creator, &minter);
+    let mint_msg = Cw721ExecuteMsg::Mint {
+        token_id: "melt".to_string(),
+        owner: venus.to_string(),
+        token_uri: None,
+        extension: None,
+    };
+    contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("minter"),
+            mint_msg.clone(),
+        )
+        .unwrap();
+    let res = contract
+        .query_token_history(deps.as_ref(), "melt".to_string(), None, None)
+        .unwrap();
+    assert!(res.history.is_empty());
+
+    let mut deps = mock_dependencies();
+    let msg = Cw721InstantiateMsg::<DefaultOptionalCollectionExtensionMsg> {
+        name: CONTRACT_NAME.to_string(),
+        symbol: SYMBOL.to_string(),
+        collection_info_extension: None,
+        minter: Some(minter.to_string()),
+        creator: Some(creator.to_string()),
+        withdraw_address: None,
+        enable_snapshots: None,
+        allow_burned_remint: None,
+        token_history_depth: Some(3),
+        token_id_strategy: None,
+        max_supply: None,
+        base_uri: None,
+        reveal: None,
+    };
+    contract
+        .instantiate(deps.as_mut(), &mock_env(), &addrs.info("creator"), msg)
+        .unwrap();
+
+    // mint, transfer, send and burn in consecutive blocks
+    let mut env = mock_env();
+    contract
+        .execute(deps.as_mut(), &env, &addrs.info("minter"), mint_msg)
+        .unwrap();
+    env.block.height += 1;
+    contract
+        .execute(
+            deps.as_mut(),
+            &env,
+            &addrs.info("venus"),
+            Cw721ExecuteMsg::TransferNft {
+                recipient: random.to_string(),
+                token_id: "melt".to_string(),
+            },
+        )
+        .unwrap();
+    let res = contract
+        .query_token_history(deps.as_ref(), "melt".to_string(), None, None)
+        .unwrap();
+    assert_eq!(
+        res.history,
+        vec![
+            TokenHistoryItem {
+                seq: 0,
+                action: TokenHistoryAction::Mint,
+                from: None,
+                to: Some(venus.to_string()),
+                height: env.block.height - 1,
+                time: env.block.time,
+            },
+            TokenHistoryItem {
+                seq: 1,
+                action: TokenHistoryAction::Transfer,
+                from: Some(venus.to_string()),
+                to: Some(random.to_string()),
+                height: env.block.height,
+                time: env.block.time,
+            },
+        ]
+    );
+    env.block.height += 1;
+    contract
+        .execute(
+            deps.as_mut(),
+            &env,
+            &addrs.info("random"),
+            Cw721ExecuteMsg::SendNft {
+                contract: venus.to_string(),
+                token_id: "melt".to_string(),
+                msg: to_json_binary("hello").unwrap(),
+            },
+        )
+        .unwrap();
+    env.block.height += 1;
+    contract
+        .execute(
+            deps.as_mut(),
+            &env,
+            &addrs.info("venus"),
+            Cw721ExecuteMsg::Burn {
+                token_id: "melt".to_string(),
+            },
+        )
+        .unwrap();
+
+    // only the last 3 entries are kept
+    let res = contract
+        .query_token_history(deps.as_ref(), "melt".to_string(), None, None)
+        .unwrap();
+    let actions: Vec<(u64, TokenHistoryAction)> = res
+        .history
+        .into_iter()
+        .map(|item| (item.seq, item.action))
+        .collect();
+    assert_eq!(
+        actions,
+        vec![
+            (1, TokenHistoryAction::Transfer),
+            (2, TokenHistoryAction::Send),
+            (3, TokenHistoryAction::Burn),
+        ]
+    );
+
+    // pagination
+    let res = contract
+        .query_token_history(deps.as_ref(), "melt".to_string(), Some(1), Some(1))
+        .unwrap();
+    assert_eq!(
+        res.history,
+        vec![TokenHistoryItem {
+            seq: 2,
+            action: TokenHistoryAction::Send,
+            from: Some(random.to_string()),
+            to: Some(venus.to_string()),
+            height: env.block.height - 1,
+            time: env.block.time,
+        }]
+    );
+}
+
+#[test]
+fn test_transfer_offers() {
+    let mut deps = mock_dependencies();
+    let mut addrs = MockAddrFactory::new(deps.api);
+    let creator = addrs.addr("creator");
+    let minter = addrs.addr("minter");
+    let contract = setup_contract(deps.as_mut(), &creator, &minter);
+    let venus = addrs.addr("venus");
+    let mars = addrs.addr("mars");
+    let random = addrs.addr("random");
+
+    for token_id in ["melt", "grow", "burn"] {
+        let mint_msg = Cw721ExecuteMsg::Mint {
+            token_id: token_id.to_string(),
+            owner: venus.to_string(),
+            token_uri: None,
+            extension: None,
+        };
+        contract
+            .execute(deps.as_mut(), &mock_env(), &addrs.info("minter"), mint_msg)
+            .unwrap();
+    }
+
+    // only owner, approved spender or operator can offer
+    let offer_msg = |token_id: &str, expires: Option<Expiration>| Cw721ExecuteMsg::OfferTransfer {
+        token_id: token_id.to_string(),
+        recipient: mars.to_string(),
+        expires,
+    };
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("random"),
+            offer_msg("melt", None),
+        )
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::Ownership(OwnershipError::NotOwner));
+
+    // cannot offer with expired expiration
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("venus"),
+            offer_msg("melt", Some(Expiration::AtHeight(1))),
+        )
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::Expired {});
+
+    let res = contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("venus"),
+            offer_msg("melt", None),
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        Response::new()
+            .add_attribute("action", "offer_transfer")
+            .add_attribute("sender", venus.to_string())
+            .add_attribute("recipient", mars.to_string())
+            .add_attribute("token_id", "melt")
+    );
+    let expires = Expiration::AtHeight(mock_env().block.height + 10);
+    for token_id in ["grow", "burn"] {
+        contract
+            .execute(
+                deps.as_mut(),
+                &mock_env(),
+                &addrs.info("venus"),
+                offer_msg(token_id, Some(expires)),
+            )
+            .unwrap();
+    }
+
+    // owner keeps custody until accepted
+    let res = contract
+        .query_owner_of(deps.as_ref(), &mock_env(), "melt".to_string(), false)
+        .unwrap();
+    assert_eq!(res.owner, venus.to_string());
+    let res = contract
+        .query_transfer_offer(deps.as_ref(), "melt".to_string())
+        .unwrap();
+    assert_eq!(
+        res,
+        TransferOfferResponse {
+            offer: Some(TransferOfferInfo {
+                token_id: "melt".to_string(),
+                offered_by: venus.to_string(),
+                recipient: mars.to_string(),
+                expires: Expiration::Never {},
+            }),
+        }
+    );
+    let query_msg = Cw721QueryMsg::TransferOffersByRecipient {
+        recipient: mars.to_string(),
+        start_after: Some("burn".to_string()),
+        limit: None,
+    };
+    let res: TransferOffersResponse = from_json(
+        contract
+            .query(deps.as_ref(), &mock_env(), query_msg)
+            .unwrap(),
+    )
+    .unwrap();
+    let token_ids: Vec<String> = res.offers.into_iter().map(|o| o.token_id).collect();
+    assert_eq!(token_ids, vec!["grow".to_string(), "melt".to_string()]);
+
+    // only recipient can accept
+    let accept_msg = |token_id: &str| Cw721ExecuteMsg::AcceptTransfer {
+        token_id: token_id.to_string(),
+    };
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("random"),
+            accept_msg("melt"),
+        )
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::NotTransferOfferRecipient {});
+    let res = contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("mars"),
+            accept_msg("melt"),
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        Response::new()
+            .add_attribute("action", "accept_transfer")
+            .add_attribute("sender", mars.to_string())
+            .add_attribute("offered_by", venus.to_string())
+            .add_attribute("token_id", "melt")
+    );
+    let res = contract
+        .query_owner_of(deps.as_ref(), &mock_env(), "melt".to_string(), false)
+        .unwrap();
+    assert_eq!(res.owner, mars.to_string());
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("mars"),
+            accept_msg("melt"),
+        )
+        .unwrap_err();
+    assert_eq!(
+        err,
+        Cw721ContractError::NoTransferOffer {
+            token_id: "melt".to_string()
+        }
+    );
+
+    // expired offer cannot be accepted
+    let mut env = mock_env();
+    env.block.height += 10;
+    let err = contract
+        .execute(deps.as_mut(), &env, &addrs.info("mars"), accept_msg("grow"))
+        .unwrap_err();
+    assert_eq!(
+        err,
+        Cw721ContractError::TransferOfferExpired {
+            token_id: "grow".to_string()
+        }
+    );
+
+    // only offerer, owner, approved spender or operator can cancel
+    let cancel_msg = Cw721ExecuteMsg::CancelTransferOffer {
+        token_id: "grow".to_string(),
+    };
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("random"),
+            cancel_msg.clone(),
+        )
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::Ownership(OwnershipError::NotOwner));
+    contract
+        .execute(deps.as_mut(), &mock_env(), &addrs.info("venus"), cancel_msg)
+        .unwrap();
+    let res = contract
+        .query_transfer_offer(deps.as_ref(), "grow".to_string())
+        .unwrap();
+    assert_eq!(res.offer, None);
+
+    // pending offers are cleared on transfer and burn
+    contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("venus"),
+            offer_msg("grow", None),
+        )
+        .unwrap();
+    let transfer_msg = Cw721ExecuteMsg::TransferNft {
+        recipient: random.to_string(),
+        token_id: "grow".to_string(),
+    };
+    contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("venus"),
+            transfer_msg,
+        )
+        .unwrap();
+    let burn_msg = Cw721ExecuteMsg::Burn {
+        token_id: "burn".to_string(),
+    };
+    contract
+        .execute(deps.as_mut(), &mock_env(), &addrs.info("venus"), burn_msg)
+        .unwrap();
+    let query_msg = Cw721QueryMsg::TransferOffersByRecipient {
+        recipient: mars.to_string(),
+        start_after: None,
+        limit: None,
+    };
+    let res: TransferOffersResponse = from_json(
+        contract
+            .query(deps.as_ref(), &mock_env(), query_msg)
+            .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(res.offers, vec![]);
+}
+
+#[test]
+fn test_permits() {
+    let mut deps = mock_dependencies();
+    let mut addrs = MockAddrFactory::new(deps.api);
+    let creator = addrs.addr("creator");
+    let minter = addrs.addr("minter");
+    let contract = setup_contract(deps.as_mut(), &creator, &minter);
+    let venus = addrs.addr("venus");
+    let mars = addrs.addr("mars");
+    let random = addrs.addr("random");
+
+    // owner holds a secp256k1 key, but has no gas tokens
+    let signing_key = SigningKey::from_bytes(&[7u8; 32].into()).unwrap();
+    let pubkey = Binary::from(
+        signing_key
+            .verifying_key()
+            .to_encoded_point(true)
+            .as_bytes(),
+    );
+    let owner = deps
+        .api
+        .addr_humanize(&pubkey_to_canonical_addr(&pubkey))
+        .unwrap();
+    let sign = |key: &SigningKey, action: PermitAction, permit: &Permit| {
+        let signature: Signature = key
+            .sign_prehash(&permit_hash(action, permit).unwrap())
+            .unwrap();
+        Binary::from(signature.to_bytes().as_slice())
+    };
+    for token_id in ["melt", "grow"] {
+        let mint_msg = Cw721ExecuteMsg::Mint {
+            token_id: token_id.to_string(),
+            owner: owner.to_string(),
+            token_uri: None,
+            extension: None,
+        };
+        contract
+            .execute(deps.as_mut(), &mock_env(), &addrs.info("minter"), mint_msg)
+            .unwrap();
+    }
+    let res = contract
+        .query_permit_nonce(deps.as_ref(), owner.to_string())
+        .unwrap();
+    assert_eq!(res, PermitNonceResponse { nonce: 0 });
+
+    // anyone can submit a permit signed by the owner
+    let env = mock_env();
+    let expires = Expiration::AtHeight(env.block.height + 10);
+    let permit = Permit {
+        token_id: "melt".to_string(),
+        spender: venus.to_string(),
+        expires,
+        nonce: 0,
+        chain_id: env.block.chain_id.clone(),
+        contract_address: env.contract.address.to_string(),
+    };
+    let permit_approve_msg = Cw721ExecuteMsg::PermitApprove {
+        permit: permit.clone(),
+        pubkey: pubkey.clone(),
+        signature: sign(&signing_key, PermitAction::Approve, &permit),
+    };
+    let res = contract
+        .execute(
+            deps.as_mut(),
+            &env,
+            &addrs.info("random"),
+            permit_approve_msg.clone(),
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        Response::new()
+            .add_attribute("action", "permit_approve")
+            .add_attribute("sender", random.to_string())
+            .add_attribute("owner", owner.to_string())
+            .add_attribute("spender", venus.to_string())
+            .add_attribute("token_id", "melt")
+    );
+    let res = contract
+        .query_approval(
+            deps.as_ref(),
+            &env,
+            "melt".to_string(),
+            venus.to_string(),
+            false,
+        )
+        .unwrap();
+    assert_eq!(res.approval.expires, expires);
+
+    // permit can't be replayed
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            &env,
+            &addrs.info("random"),
+            permit_approve_msg,
+        )
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::InvalidPermitNonce { expected: 1 });
+
+    let permit = Permit {
+        token_id: "grow".to_string(),
+        spender: mars.to_string(),
+        nonce: 1,
+        ..permit
+    };
+    let invalid_msgs = [
+        // signed for approval, not for transfer
+        (
+            Cw721ExecuteMsg::PermitTransfer {
+                permit: permit.clone(),
+                pubkey: pubkey.clone(),
+                signature: sign(&signing_key, PermitAction::Approve, &permit),
+            },
+            Cw721ContractError::InvalidPermitSignature {},
+        ),
+        // signed by another key
+        (
+            Cw721ExecuteMsg::PermitTransfer {
+                permit: permit.clone(),
+                pubkey: pubkey.clone(),
+                signature: sign(
+                    &SigningKey::from_bytes(&[8u8; 32].into()).unwrap(),
+                    PermitAction::Transfer,
+                    &permit,
+                ),
+            },
+            Cw721ContractError::InvalidPermitSignature {},
+        ),
+        // public key of another owner
+        (
+            Cw721ExecuteMsg::PermitTransfer {
+                permit: permit.clone(),
+                pubkey: Binary::from(
+                    SigningKey::from_bytes(&[8u8; 32].into())
+                        .unwrap()
+                        .verifying_key()
+                        .to_encoded_point(true)
+                        .as_bytes(),
+                ),
+                signature: sign(&signing_key, PermitAction::Transfer, &permit),
+            },
+            Cw721ContractError::PermitPubkeyMismatch {},
+        ),
+        // other chain
+        (
+            Cw721ExecuteMsg::PermitTransfer {
+                permit: Permit {
+                    chain_id: "other-chain".to_string(),
+                    ..permit.clone()
+                },
+                pubkey: pubkey.clone(),
+                signature: sign(&signing_key, PermitAction::Transfer, &permit),
+            },
+            Cw721ContractError::InvalidPermitDomain {},
+        ),
+        // expired
+        (
+            Cw721ExecuteMsg::PermitTransfer {
+                permit: Permit {
+                    expires: Expiration::AtHeight(1),
+                    ..permit.clone()
+                },
+                pubkey: pubkey.clone(),
+                signature: sign(&signing_key, PermitAction::Transfer, &permit),
+            },
+            Cw721ContractError::PermitExpired {},
+        ),
+    ];
+    for (msg, expected) in invalid_msgs {
+        let err = contract
+            .execute(deps.as_mut(), &env, &addrs.info("random"), msg)
+            .unwrap_err();
+        assert_eq!(err, expected);
+    }
+
+    let permit_transfer_msg = Cw721ExecuteMsg::PermitTransfer {
+        permit: permit.clone(),
+        pubkey,
+        signature: sign(&signing_key, PermitAction::Transfer, &permit),
+    };
+    let res = contract
+        .execute(
+            deps.as_mut(),
+            &env,
+            &addrs.info("random"),
+            permit_transfer_msg,
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        Response::new()
+            .add_attribute("action", "permit_transfer")
+            .add_attribute("sender", random.to_string())
+            .add_attribute("owner", owner.to_string())
+            .add_attribute("recipient", mars.to_string())
+            .add_attribute("token_id", "grow")
+    );
+    let res = contract
+        .query_owner_of(deps.as_ref(), &env, "grow".to_string(), false)
+        .unwrap();
+    assert_eq!(res.owner, mars.to_string());
+    let query_msg = Cw721QueryMsg::PermitNonce {
+        owner: owner.to_string(),
+    };
+    let res: PermitNonceResponse =
+        from_json(contract.query(deps.as_ref(), &env, query_msg).unwrap()).unwrap();
+    assert_eq!(res, PermitNonceResponse { nonce: 2 });
+}
+
+#[test]
+fn test_redeem_voucher() {
+    let mut deps = mock_dependencies();
+    let mut addrs = MockAddrFactory::new(deps.api);
+    let creator = addrs.addr("creator");
+    let minter = addrs.addr("minter");
+    let contract = setup_contract(deps.as_mut(), &creator, &minter);
+    let venus = addrs.addr("venus");
+    let random = addrs.addr("random");
+
+    let signing_key = SigningKey::from_bytes(&[7u8; 32].into()).unwrap();
+    let pubkey = Binary::from(
+        signing_key
+            .verifying_key()
+            .to_encoded_point(true)
+            .as_bytes(),
+    );
+    let sign = |key: &SigningKey, voucher: &MintVoucher<DefaultOptionalNftExtensionMsg>| {
+        let signature: Signature = key.sign_prehash(&voucher_hash(voucher).unwrap()).unwrap();
+        Binary::from(signature.to_bytes().as_slice())
+    };
+    let env = mock_env();
+    let voucher = MintVoucher {
+        token_id: "melt".to_string(),
+        token_uri: Some("ipfs://melt".to_string()),
+        extension: None,
+        price: Some(Coin::new(100u128, "uatom")),
+        recipients: Some(vec![venus.to_string()]),
+        expires: Expiration::AtHeight(env.block.height + 10),
+        chain_id: env.block.chain_id.clone(),
+        contract_address: env.contract.address.to_string(),
+    };
+    let redeem_msg = Cw721ExecuteMsg::RedeemVoucher {
+        voucher: voucher.clone(),
+        signature: sign(&signing_key, &voucher),
+    };
+    let paid_info = message_info(&venus, &[Coin::new(100u128, "uatom")]);
+
+    // lazy minting is disabled without voucher signer
+    let err = contract
+        .execute(deps.as_mut(), &env, &paid_info, redeem_msg.clone())
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::NoVoucherSigner {});
+
+    // only minter can set voucher signer
+    let set_signer_msg = Cw721ExecuteMsg::SetVoucherSigner {
+        pubkey: Some(pubkey.clone()),
+    };
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            &env,
+            &addrs.info("random"),
+            set_signer_msg.clone(),
+        )
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::NotMinter {});
+    contract
+        .execute(deps.as_mut(), &env, &addrs.info("minter"), set_signer_msg)
+        .unwrap();
+    let res = contract.query_voucher_signer(deps.as_ref()).unwrap();
+    assert_eq!(res.pubkey, Some(pubkey));
+
+    let invalid_redemptions = [
+        // not an allowed recipient
+        (
+            message_info(&random, &[Coin::new(100u128, "uatom")]),
+            redeem_msg.clone(),
+            Cw721ContractError::VoucherRecipientNotAllowed {},
+        ),
+        // no payment
+        (
+            addrs.info("venus"),
+            redeem_msg.clone(),
+            Cw721ContractError::Payment(PaymentError::NoFunds {}),
+        ),
+        // wrong amount
+        (
+            message_info(&venus, &[Coin::new(50u128, "uatom")]),
+            redeem_msg.clone(),
+            Cw721ContractError::InvalidVoucherPayment {
+                expected: "100uatom".to_string(),
+            },
+        ),
+        // not signed by voucher signer
+        (
+            paid_info.clone(),
+            Cw721ExecuteMsg::RedeemVoucher {
+                voucher: voucher.clone(),
+                signature: sign(
+                    &SigningKey::from_bytes(&[8u8; 32].into()).unwrap(),
+                    &voucher,
+                ),
+            },
+            Cw721ContractError::InvalidVoucherSignature {},
+        ),
+        // tampered voucher
+        (
+            paid_info.clone(),
+            Cw721ExecuteMsg::RedeemVoucher {
+                voucher: MintVoucher {
+                    price: None,
+                    ..voucher.clone()
+                },
+                signature: sign(&signing_key, &voucher),
+            },
+            Cw721ContractError::InvalidVoucherSignature {},
+        ),
+    ];
+    for (info, msg, expected) in invalid_redemptions {
+        let err = contract
+            .execute(deps.as_mut(), &env, &info, msg)
+            .unwrap_err();
+        assert_eq!(err, expected);
+    }
+
+    // expired voucher
+    let mut expired_env = mock_env();
+    expired_env.block.height += 10;
+    let err = contract
+        .execute(deps.as_mut(), &expired_env, &paid_info, redeem_msg.clone())
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::VoucherExpired {});
+
+    // minted on behalf of the minter
+    let hash = HexBinary::from(voucher_hash(&voucher).unwrap());
+    let res = contract
+        .execute(deps.as_mut(), &env, &paid_info, redeem_msg.clone())
+        .unwrap();
+    assert_eq!(
+        res,
+        Response::new()
+            .set_data(
+                to_json_binary(&MintResponse {
+                    token_id: "melt".to_string()
+                })
+                .unwrap()
+            )
+            .add_attribute("action", "mint")
+            .add_attribute("minter", minter.to_string())
+            .add_attribute("owner", venus.to_string())
+            .add_attribute("token_id", "melt")
+            .add_attribute("token_uri", "ipfs://melt")
+            .add_attribute("redeemed_by", venus.to_string())
+            .add_attribute("voucher_hash", hash.to_hex())
+    );
+    let res = contract
+        .query_owner_of(deps.as_ref(), &env, "melt".to_string(), false)
+        .unwrap();
+    assert_eq!(res.owner, venus.to_string());
+    let query_msg = Cw721QueryMsg::IsVoucherRedeemed { voucher_hash: hash };
+    let res: IsVoucherRedeemedResponse =
+        from_json(contract.query(deps.as_ref(), &env, query_msg).unwrap()).unwrap();
+    assert!(res.redeemed);
+
+    // voucher can only be redeemed once
+    let err = contract
+        .execute(deps.as_mut(), &env, &paid_info, redeem_msg)
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::VoucherAlreadyRedeemed {});
+}
+
+#[test]
+fn test_merkle_claims() {
+    let mut deps = mock_dependencies();
+    let mut addrs = MockAddrFactory::new(deps.api);
+    let creator = addrs.addr("creator");
+    let minter = addrs.addr("minter");
+    let contract = setup_contract(deps.as_mut(), &creator, &minter);
+    let venus = addrs.addr("venus");
+    let mars = addrs.addr("mars");
+    let random = addrs.addr("random");
+
+    // allowlist with 4 leaves
+    let leaves: Vec<HexBinary> = [
+        (&venus, "melt"),
+        (&venus, "grow"),
+        (&mars, "turn"),
+        (&random, "burn"),
+    ]
+    .into_iter()
+    .map(|(address, token_id)| {
+        let token_uri = Some(format!("ipfs://{token_id}"));
+        claim_leaf::<DefaultOptionalNftExtensionMsg>(address.as_str(), token_id, token_uri, None)
+            .unwrap()
+    })
+    .collect();
+    let left = merkle_parent(&leaves[0], &leaves[1]);
+    let right = merkle_parent(&leaves[2], &leaves[3]);
+    let merkle_root = HexBinary::from(merkle_parent(&left, &right));
+    let proof = vec![leaves[1].clone(), HexBinary::from(right)];
+    let claim_msg = Cw721ExecuteMsg::Claim {
+        token_id: "melt".to_string(),
+        token_uri: Some("ipfs://melt".to_string()),
+        extension: None,
+        proof: proof.clone(),
+    };
+
+    // claims are disabled without merkle root
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("venus"),
+            claim_msg.clone(),
+        )
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::NoMerkleRoot {});
+
+    // only minter can set merkle root
+    let set_root_msg = Cw721ExecuteMsg::SetMerkleRoot {
+        merkle_root: Some(merkle_root.clone()),
+    };
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("random"),
+            set_root_msg.clone(),
+        )
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::NotMinter {});
+    let invalid_root_msg = Cw721ExecuteMsg::SetMerkleRoot {
+        merkle_root: Some(HexBinary::from(vec![1u8; 20])),
+    };
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("minter"),
+            invalid_root_msg,
+        )
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::InvalidMerkleRoot {});
+    let res = contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("minter"),
+            set_root_msg,
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        Response::new()
+            .add_attribute("action", "set_merkle_root")
+            .add_attribute("sender", minter.to_string())
+            .add_attribute("merkle_root", merkle_root.to_hex())
+    );
+    let res = contract.query_merkle_root(deps.as_ref()).unwrap();
+    assert_eq!(res.merkle_root, Some(merkle_root));
+
+    // leaf of another address
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("random"),
+            claim_msg.clone(),
+        )
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::InvalidMerkleProof {});
+    // other metadata than in allowlist
+    let tampered_msg = Cw721ExecuteMsg::Claim {
+        token_id: "melt".to_string(),
+        token_uri: Some("ipfs://other".to_string()),
+        extension: None,
+        proof,
+    };
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("venus"),
+            tampered_msg,
+        )
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::InvalidMerkleProof {});
+
+    // claimed on behalf of the minter
+    let res = contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("venus"),
+            claim_msg.clone(),
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        Response::new()
+            .set_data(
+                to_json_binary(&MintResponse {
+                    token_id: "melt".to_string()
+                })
+                .unwrap()
+            )
+            .add_attribute("action", "mint")
+            .add_attribute("minter", minter.to_string())
+            .add_attribute("owner", venus.to_string())
+            .add_attribute("token_id", "melt")
+            .add_attribute("token_uri", "ipfs://melt")
+            .add_attribute("claimed_by", venus.to_string())
+            .add_attribute("leaf", leaves[0].to_hex())
+    );
+    let res = contract
+        .query_owner_of(deps.as_ref(), &mock_env(), "melt".to_string(), false)
+        .unwrap();
+    assert_eq!(res.owner, venus.to_string());
+    for (leaf, claimed) in [(&leaves[0], true), (&leaves[1], false)] {
+        let query_msg = Cw721QueryMsg::IsClaimed { leaf: leaf.clone() };
+        let res: IsClaimedResponse = from_json(
+            contract
+                .query(deps.as_ref(), &mock_env(), query_msg)
+                .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(res.claimed, claimed);
+    }
+
+    // leaf can only be claimed once
+    let err = contract
+        .execute(deps.as_mut(), &mock_env(), &addrs.info("venus"), claim_msg)
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::LeafAlreadyClaimed {});
+}
+
+#[test]
+fn test_hooks() {
+    let mut deps = mock_dependencies();
+    let mut addrs = MockAddrFactory::new(deps.api);
+    let creator = addrs.addr("creator");
+    let minter = addrs.addr("minter");
+    let contract = setup_contract(deps.as_mut(), &creator, &minter);
+    let venus = addrs.addr("venus");
+    let mars = addrs.addr("mars");
+    let staking = addrs.addr("staking");
+    let analytics = addrs.addr("analytics");
+
+    // only creator can add hooks
+    let add_hook_msg = |hook: &Addr, on_error: HookOnError| Cw721ExecuteMsg::AddHook {
+        contract_addr: hook.to_string(),
+        on_error,
+    };
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("minter"),
+            add_hook_msg(&staking, HookOnError::Fail),
+        )
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::NotCreator {});
+    for (hook, on_error) in [
+        (&staking, HookOnError::Fail),
+        (&analytics, HookOnError::Ignore),
+    ] {
+        contract
+            .execute(
+                deps.as_mut(),
+                &mock_env(),
+                &addrs.info("creator"),
+                add_hook_msg(hook, on_error),
+            )
+            .unwrap();
+    }
+    let query_msg = Cw721QueryMsg::Hooks {
+        start_after: None,
+        limit: None,
+    };
+    let res: HooksResponse = from_json(
+        contract
+            .query(deps.as_ref(), &mock_env(), query_msg.clone())
+            .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(res.hooks.len(), 2);
+    assert!(res.hooks.contains(&Hook {
+        contract_addr: analytics.to_string(),
+        on_error: HookOnError::Ignore,
+    }));
+
+    // each hook is notified on mint, transfer and burn
+    let hook_submsgs = |action: TokenHistoryAction, from: Option<&Addr>, to: Option<&Addr>| {
+        let hook_msg = Cw721HookMsg {
+            token_id: "melt".to_string(),
+            from: from.map(|addr| addr.to_string()),
+            to: to.map(|addr| addr.to_string()),
+            action,
+        };
+        let mut submsgs: Vec<SubMsg> = vec![
+            SubMsg::new(hook_msg.clone().into_cosmos_msg(&staking).unwrap()),
+            SubMsg::reply_on_error(hook_msg.into_cosmos_msg(&analytics).unwrap(), HOOK_REPLY_ID),
+        ];
+        // hooks are ordered by address
+        if analytics < staking {
+            submsgs.reverse();
+        }
+        submsgs
+    };
+    let mint_msg = Cw721ExecuteMsg::Mint {
+        token_id: "melt".to_string(),
+        owner: venus.to_string(),
+        token_uri: None,
+        extension: None,
+    };
+    let res = contract
+        .execute(deps.as_mut(), &mock_env(), &addrs.info("minter"), mint_msg)
+        .unwrap();
+    assert_eq!(
+        res.messages,
+        hook_submsgs(TokenHistoryAction::Mint, None, Some(&venus))
+    );
+    let transfer_msg = Cw721ExecuteMsg::TransferNft {
+        recipient: mars.to_string(),
+        token_id: "melt".to_string(),
+    };
+    let res = contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("venus"),
+            transfer_msg,
+        )
+        .unwrap();
+    assert_eq!(
+        res.messages,
+        hook_submsgs(TokenHistoryAction::Transfer, Some(&venus), Some(&mars))
+    );
+    let burn_msg = Cw721ExecuteMsg::Burn {
+        token_id: "melt".to_string(),
+    };
+    let res = contract
+        .execute(deps.as_mut(), &mock_env(), &addrs.info("mars"), burn_msg)
+        .unwrap();
+    assert_eq!(
+        res.messages,
+        hook_submsgs(TokenHistoryAction::Burn, Some(&mars), None)
+    );
+
+    // errors of ignoring hooks are handled in reply
+    let reply = |id: u64| Reply {
+        id,
+        payload: Binary::default(),
+        gas_used: 1000,
+        result: SubMsgResult::Err("hook failed".to_string()),
+    };
+    let res = contract
+        .reply(deps.as_mut(), &mock_env(), reply(HOOK_REPLY_ID))
+        .unwrap();
+    assert_eq!(
+        res,
+        Response::new()
+            .add_attribute("action", "hook_failed")
+            .add_attribute("error", "hook failed")
+    );
+    let err = contract
+        .reply(deps.as_mut(), &mock_env(), reply(1))
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::UnknownReplyId { id: 1 });
+
+    // only creator can remove hooks
+    let remove_hook_msg = Cw721ExecuteMsg::RemoveHook {
+        contract_addr: staking.to_string(),
+    };
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("minter"),
+            remove_hook_msg.clone(),
+        )
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::NotCreator {});
+    contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("creator"),
+            remove_hook_msg.clone(),
+        )
+        .unwrap();
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("creator"),
+            remove_hook_msg,
+        )
+        .unwrap_err();
+    assert_eq!(
+        err,
+        Cw721ContractError::HookNotFound {
+            contract_addr: staking.to_string()
+        }
+    );
+    let res: HooksResponse = from_json(
+        contract
+            .query(deps.as_ref(), &mock_env(), query_msg)
+            .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        res.hooks,
+        vec![Hook {
+            contract_addr: analytics.to_string(),
+            on_error: HookOnError::Ignore,
+        }]
+    );
+}
+
+#[test]
+fn test_lifecycle_hooks() {
+    /// Contract blocking transfers and approvals to a given address, protecting a token from burns
+    /// and tagging mints and transfers, by only overriding the lifecycle hooks.
+    struct HookedContract {
+        blocked: Addr,
+    }
+
+    impl
+        Cw721Execute<
+            DefaultOptionalNftExtension,
+            DefaultOptionalNftExtensionMsg,
+            DefaultOptionalCollectionExtension,
+            DefaultOptionalCollectionExtensionMsg,
+            Empty,
+            Empty,
+        > for HookedContract
+    {
+        fn before_transfer(
+            &self,
+            _deps: Deps,
+            _env: &Env,
+            _info: &MessageInfo,
+            recipient: &str,
+            _token_id: &str,
+        ) -> Result<(), Cw721ContractError> {
+            if recipient == self.blocked.as_str() {
+                return Err(StdError::generic_err("recipient blocked").into());
+            }
+            Ok(())
+        }
+
+        fn after_transfer(
+            &self,
+            _deps: DepsMut,
+            _env: &Env,
+            info: &MessageInfo,
+            recipient: &str,
+            _token_id: &str,
+            response: Response,
+        ) -> Result<Response, Cw721ContractError> {
+            Ok(response.add_attribute("hooked_transfer", format!("{}>{recipient}", info.sender)))
+        }
+
+        fn after_mint(
+            &self,
+            _deps: DepsMut,
+            _env: &Env,
+            _info: &MessageInfo,
+            _owner: &str,
+            token_id: &str,
+            response: Response,
+        ) -> Result<Response, Cw721ContractError> {
+            Ok(response.add_attribute("hooked_mint", token_id))
+        }
+
+        fn before_burn(
+            &self,
+            _deps: Deps,
+            _env: &Env,
+            _info: &MessageInfo,
+            token_id: &str,
+        ) -> Result<(), Cw721ContractError> {
+            if token_id == "protected" {
+                return Err(StdError::generic_err("token protected").into());
+            }
+            Ok(())
+        }
+
+        fn before_approve(
+            &self,
+            _deps: Deps,
+            _env: &Env,
+            info: &MessageInfo,
+            spender: &str,
+            _token_id: Option<&str>,
+        ) -> Result<(), Cw721ContractError> {
+            if spender == self.blocked.as_str() {
+                let msg = format!("spender blocked for {}", info.sender);
+                return Err(StdError::generic_err(msg).into());
+            }
+            Ok(())
+        }
+    }
+
+    let mut deps = mock_dependencies();
+    let mut addrs = MockAddrFactory::new(deps.api);
+    let creator = addrs.addr("creator");
+    let minter = addrs.addr("minter");
+    setup_contract(deps.as_mut(), &creator, &minter);
+    let venus = addrs.addr("venus");
+    let blocked = addrs.addr("blocked");
+    let contract = HookedContract {
+        blocked: blocked.clone(),
+    };
+
+    // after mint hook extends response of mint and batch mint
+    let res = contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("minter"),
+            Cw721ExecuteMsg::Mint {
+                token_id: "protected".to_string(),
+                owner: venus.to_string(),
+                token_uri: None,
+                extension: None,
+            },
+        )
+        .unwrap();
+    assert!(res
+        .attributes
+        .contains(&Attribute::new("hooked_mint", "protected")));
+    let mint_msg = |token_id: &str| MintMsg {
+        token_id: token_id.to_string(),
+        owner: venus.to_string(),
+        token_uri: None,
+        extension: None,
+    };
+    let res = contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("minter"),
+            Cw721ExecuteMsg::BatchMint {
+                tokens: vec![mint_msg("melt"), mint_msg("grow")],
+            },
+        )
+        .unwrap();
+    assert!(res
+        .attributes
+        .contains(&Attribute::new("hooked_mint", "melt")));
+    assert!(res
+        .attributes
+        .contains(&Attribute::new("hooked_mint", "grow")));
+
+    // before transfer hook rejects transfers, sends and batch transfers
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("venus"),
+            Cw721ExecuteMsg::TransferNft {
+                recipient: blocked.to_string(),
+                token_id: "melt".to_string(),
+            },
+        )
+        .unwrap_err();
+    assert_eq!(
+        err,
+        Cw721ContractError::Std(StdError::generic_err("recipient blocked"))
+    );
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("venus"),
+            Cw721ExecuteMsg::SendNft {
+                contract: blocked.to_string(),
+                token_id: "melt".to_string(),
+                msg: Binary::default(),
+            },
+        )
+        .unwrap_err();
+    assert_eq!(
+        err,
+        Cw721ContractError::Std(StdError::generic_err("recipient blocked"))
+    );
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("venus"),
+            Cw721ExecuteMsg::BatchTransferNft {
+                recipient: blocked.to_string(),
+                token_ids: vec!["melt".to_string(), "grow".to_string()],
+            },
+        )
+        .unwrap_err();
+    assert_eq!(
+        err,
+        Cw721ContractError::Std(StdError::generic_err("recipient blocked"))
+    );
+    let res: OwnerOfResponse = from_json(
+        Cw721OnchainExtensions::default()
+            .query(
+                deps.as_ref(),
+                &mock_env(),
+                Cw721QueryMsg::OwnerOf {
+                    token_id: "melt".to_string(),
+                    include_expired: None,
+                },
+            )
+            .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(res.owner, venus.to_string());
+
+    // before approve hook rejects approvals and operators
+    let spender_blocked = Cw721ContractError::Std(StdError::generic_err(format!(
+        "spender blocked for {venus}"
+    )));
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("venus"),
+            Cw721ExecuteMsg::Approve {
+                spender: blocked.to_string(),
+                token_id: "melt".to_string(),
+                expires: None,
+            },
+        )
+        .unwrap_err();
+    assert_eq!(err, spender_blocked);
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("venus"),
+            Cw721ExecuteMsg::ApproveAll {
+                operator: blocked.to_string(),
+                expires: None,
+            },
+        )
+        .unwrap_err();
+    assert_eq!(err, spender_blocked);
+
+    // hooks get info of the offerer on accepting a transfer offer
+    let mars = addrs.addr("mars");
+    contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("venus"),
+            Cw721ExecuteMsg::OfferTransfer {
+                token_id: "grow".to_string(),
+                recipient: mars.to_string(),
+                expires: None,
+            },
+        )
+        .unwrap();
+    let res = contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("mars"),
+            Cw721ExecuteMsg::AcceptTransfer {
+                token_id: "grow".to_string(),
+            },
+        )
+        .unwrap();
+    assert!(res.attributes.contains(&Attribute::new(
+        "hooked_transfer",
+        format!("{venus}>{mars}")
+    )));
+
+    // hooks get info of the permit signer, not of the relayer
+    let signing_key = SigningKey::from_bytes(&[7u8; 32].into()).unwrap();
+    let pubkey = Binary::from(
+        signing_key
+            .verifying_key()
+            .to_encoded_point(true)
+            .as_bytes(),
+    );
+    let signer = deps
+        .api
+        .addr_humanize(&pubkey_to_canonical_addr(&pubkey))
+        .unwrap();
+    contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("minter"),
+            Cw721ExecuteMsg::Mint {
+                token_id: "signed".to_string(),
+                owner: signer.to_string(),
+                token_uri: None,
+                extension: None,
+            },
+        )
+        .unwrap();
+    let env = mock_env();
+    let permit = |spender: &Addr, nonce: u64| Permit {
+        token_id: "signed".to_string(),
+        spender: spender.to_string(),
+        expires: Expiration::Never {},
+        nonce,
+        chain_id: env.block.chain_id.clone(),
+        contract_address: env.contract.address.to_string(),
+    };
+    let sign = |action: PermitAction, permit: &Permit| {
+        let signature: Signature = signing_key
+            .sign_prehash(&permit_hash(action, permit).unwrap())
+            .unwrap();
+        Binary::from(signature.to_bytes().as_slice())
+    };
+    let blocked_permit = permit(&blocked, 0);
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            &env,
+            &addrs.info("random"),
+            Cw721ExecuteMsg::PermitApprove {
+                permit: blocked_permit.clone(),
+                pubkey: pubkey.clone(),
+                signature: sign(PermitAction::Approve, &blocked_permit),
+            },
+        )
+        .unwrap_err();
+    assert_eq!(
+        err,
+        Cw721ContractError::Std(StdError::generic_err(format!(
+            "spender blocked for {signer}"
+        )))
+    );
+    let mars_permit = permit(&mars, 0);
+    let res = contract
+        .execute(
+            deps.as_mut(),
+            &env,
+            &addrs.info("random"),
+            Cw721ExecuteMsg::PermitTransfer {
+                permit: mars_permit.clone(),
+                pubkey,
+                signature: sign(PermitAction::Transfer, &mars_permit),
+            },
+        )
+        .unwrap();
+    assert!(res.attributes.contains(&Attribute::new(
+        "hooked_transfer",
+        format!("{signer}>{mars}")
+    )));
+
+    // before burn hook rejects burns, other tokens are burned as usual
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("venus"),
+            Cw721ExecuteMsg::BatchBurn {
+                token_ids: vec!["melt".to_string(), "protected".to_string()],
+            },
+        )
+        .unwrap_err();
+    assert_eq!(
+        err,
+        Cw721ContractError::Std(StdError::generic_err("token protected"))
+    );
+    contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("venus"),
+            Cw721ExecuteMsg::Burn {
+                token_id: "melt".to_string(),
+            },
+        )
+        .unwrap();
+}
+
+#[test]
+fn test_approve_all_revoke_all() {
+    let mut deps = mock_dependencies();
+    let mut addrs = MockAddrFactory::new(deps.api);
+    let creator = addrs.addr("creator");
+    let minter = addrs.addr("minter");
+    let contract = setup_contract(deps.as_mut(), &creator, &minter);
+
+    // Mint a couple tokens (from the same owner)
+    let token_id1 = "grow1".to_string();
+    let token_uri1 = "https://www.merriam-webster.com/dictionary/grow1".to_string();
+
+    let token_id2 = "grow2".to_string();
+    let token_uri2 = "https://www.merriam-webster.com/dictionary/grow2".to_string();
+
+    let mint_msg1 = Cw721ExecuteMsg::Mint {
+        token_id: token_id1.clone(),
+        owner: addrs.addr("demeter").to_string(),
+        token_uri: Some(token_uri1),
+        extension: None,
+    };
+
+    let minter_info = addrs.info("minter");
+    contract
+        .execute(deps.as_mut(), &mock_env(), &minter_info, mint_msg1)
+        .unwrap();
+
+    let mint_msg2 = Cw721ExecuteMsg::Mint {
+        token_id: token_id2.clone(),
+        owner: addrs.addr("demeter").to_string(),
+        token_uri: Some(token_uri2),
+        extension: None,
     };
 
     let env = mock_env();
@@ -1777,6 +5727,388 @@ fn test_approve_all_revoke_all() {
     }
 }
 
+#[test]
+fn test_royalty_queries() {
+    let mut deps = mock_dependencies();
+    let contract = Cw721OnchainExtensions::default();
+    let mut addrs = MockAddrFactory::new(deps.api);
+    let creator = addrs.addr("creator");
+    let minter = addrs.addr("minter");
+    let payment = addrs.addr("payment");
+    let msg = Cw721InstantiateMsg::<DefaultOptionalCollectionExtensionMsg> {
+        name: CONTRACT_NAME.to_string(),
+        symbol: SYMBOL.to_string(),
+        collection_info_extension: Some(CollectionExtensionMsg {
+            description: Some("description".to_string()),
+            image: Some("https://moonphases.org".to_string()),
+            explicit_content: None,
+            external_link: None,
+            start_trading_time: None,
+            royalty_info: Some(RoyaltyInfoResponse {
+                payment_address: payment.to_string(),
+                share: "0.1".parse().unwrap(),
+            }),
+        }),
+        minter: Some(minter.to_string()),
+        creator: Some(creator.to_string()),
+        withdraw_address: None,
+        enable_snapshots: None,
+        allow_burned_remint: None,
+        token_history_depth: None,
+        token_id_strategy: None,
+        max_supply: None,
+        base_uri: None,
+        reveal: None,
+    };
+    contract
+        .instantiate(deps.as_mut(), &mock_env(), &addrs.info("creator"), msg)
+        .unwrap();
+    let mint_msg = Cw721ExecuteMsg::Mint {
+        token_id: "melt".to_string(),
+        owner: minter.to_string(),
+        token_uri: None,
+        extension: None,
+    };
+    contract
+        .execute(deps.as_mut(), &mock_env(), &addrs.info("minter"), mint_msg)
+        .unwrap();
+
+    let res = contract.query_check_royalties(deps.as_ref()).unwrap();
+    assert!(res.royalty_payments);
+    // royalty amount is rounded down
+    let res = contract
+        .query_royalty_info(deps.as_ref(), "melt".to_string(), Uint128::new(1005))
+        .unwrap();
+    assert_eq!(
+        res,
+        RoyaltiesInfoResponse {
+            address: payment.to_string(),
+            royalty_amount: Uint128::new(100),
+        }
+    );
+    // unknown token
+    contract
+        .query_royalty_info(deps.as_ref(), "unknown".to_string(), Uint128::new(1000))
+        .unwrap_err();
+
+    // no royalties without royalty info
+    let mut deps = mock_dependencies();
+    let contract = setup_contract(deps.as_mut(), &creator, &minter);
+    let mint_msg = Cw721ExecuteMsg::Mint {
+        token_id: "melt".to_string(),
+        owner: minter.to_string(),
+        token_uri: None,
+        extension: None,
+    };
+    contract
+        .execute(deps.as_mut(), &mock_env(), &addrs.info("minter"), mint_msg)
+        .unwrap();
+    let res = contract.query_check_royalties(deps.as_ref()).unwrap();
+    assert!(!res.royalty_payments);
+    let res = contract
+        .query_royalty_info(deps.as_ref(), "melt".to_string(), Uint128::new(1000))
+        .unwrap();
+    assert_eq!(res.royalty_amount, Uint128::zero());
+}
+
+#[test]
+fn test_migrate_legacy_nft_info() {
+    let mut deps = mock_dependencies();
+    let mut addrs = MockAddrFactory::new(deps.api);
+    let creator = addrs.addr("creator");
+    let minter = addrs.addr("minter");
+    let venus = addrs.addr("venus");
+    let random = addrs.addr("random");
+    let contract = setup_contract(deps.as_mut(), &creator, &minter);
+    // legacy collection, created before balances were tracked
+    contract
+        .config
+        .balances_migrated
+        .remove(deps.as_mut().storage);
+
+    // NFTs with metadata (and approvals) stored within ownership record
+    let legacy_nft_info: Map<&str, LegacyNftInfo<DefaultOptionalNftExtension>> = Map::new("tokens");
+    legacy_nft_info
+        .save(
+            deps.as_mut().storage,
+            "melt",
+            &LegacyNftInfo {
+                owner: venus.clone(),
+                approvals: Some(vec![Approval {
+                    spender: random.clone(),
+                    expires: Expiration::Never {},
+                }]),
+                token_uri: None,
+                extension: None,
+            },
+        )
+        .unwrap();
+    legacy_nft_info
+        .save(
+            deps.as_mut().storage,
+            "grow",
+            &LegacyNftInfo {
+                owner: venus.clone(),
+                approvals: None,
+                token_uri: Some("https://www.merriam-webster.com/dictionary/grow".to_string()),
+                extension: None,
+            },
+        )
+        .unwrap();
+
+    let res = contract
+        .migrate(
+            deps.as_mut(),
+            mock_env(),
+            Cw721MigrateMsg::WithUpdate {
+                minter: None,
+                creator: None,
+            },
+            "contract_name",
+            "contract_version",
+        )
+        .unwrap();
+    assert!(res
+        .attributes
+        .contains(&Attribute::new("migrated_nft_info", "2")));
+    // balances are backfilled
+    assert!(res
+        .attributes
+        .contains(&Attribute::new("migrated_balances", "2")));
+    let balance = contract
+        .query_balance_of(deps.as_ref(), venus.to_string())
+        .unwrap();
+    assert_eq!(balance.balance, 2);
+
+    // metadata and approvals are moved out of ownership record
+    let approvals = contract
+        .query_approvals(deps.as_ref(), &mock_env(), "melt".to_string(), false)
+        .unwrap();
+    assert_eq!(
+        approvals.approvals,
+        vec![Approval {
+            spender: random.clone(),
+            expires: Expiration::Never {},
+        }]
+    );
+    let nft_info = contract
+        .query_all_nft_info(deps.as_ref(), &mock_env(), "grow".to_string(), false)
+        .unwrap();
+    assert_eq!(nft_info.access.owner, venus.to_string());
+    assert_eq!(
+        nft_info.info.token_uri,
+        Some("https://www.merriam-webster.com/dictionary/grow".to_string())
+    );
+
+    // approved spender can still transfer
+    let transfer_msg = Cw721ExecuteMsg::TransferNft {
+        recipient: random.to_string(),
+        token_id: "melt".to_string(),
+    };
+    contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("random"),
+            transfer_msg,
+        )
+        .unwrap();
+    let approvals = contract
+        .query_approvals(deps.as_ref(), &mock_env(), "melt".to_string(), true)
+        .unwrap();
+    assert!(approvals.approvals.is_empty());
+    let balance = contract
+        .query_balance_of(deps.as_ref(), venus.to_string())
+        .unwrap();
+    assert_eq!(balance.balance, 1);
+
+    // migrating again is a no-op
+    let res = contract
+        .migrate(
+            deps.as_mut(),
+            mock_env(),
+            Cw721MigrateMsg::WithUpdate {
+                minter: None,
+                creator: None,
+            },
+            "contract_name",
+            "contract_version",
+        )
+        .unwrap();
+    assert!(!res
+        .attributes
+        .iter()
+        .any(|attr| attr.key == "migrated_nft_info" || attr.key == "migrated_balances"));
+    let balance = contract
+        .query_balance_of(deps.as_ref(), random.to_string())
+        .unwrap();
+    assert_eq!(balance.balance, 1);
+
+    // large collections are migrated in multiple migrations
+    let mut deps = mock_dependencies();
+    let contract = setup_contract(deps.as_mut(), &creator, &minter);
+    contract
+        .config
+        .balances_migrated
+        .remove(deps.as_mut().storage);
+    for i in 0..LEGACY_NFT_INFO_MIGRATION_LIMIT + 1 {
+        legacy_nft_info
+            .save(
+                deps.as_mut().storage,
+                &format!("{i:04}"),
+                &LegacyNftInfo {
+                    owner: venus.clone(),
+                    approvals: Some(vec![Approval {
+                        spender: random.clone(),
+                        expires: Expiration::Never {},
+                    }]),
+                    token_uri: Some(format!("ipfs://legacy/{i}")),
+                    extension: None,
+                },
+            )
+            .unwrap();
+    }
+    let migrate_msg = Cw721MigrateMsg::WithUpdate {
+        minter: None,
+        creator: None,
+    };
+    let res = contract
+        .migrate(
+            deps.as_mut(),
+            mock_env(),
+            migrate_msg.clone(),
+            "contract_name",
+            "contract_version",
+        )
+        .unwrap();
+    let last_token_id = format!("{:04}", LEGACY_NFT_INFO_MIGRATION_LIMIT - 1);
+    assert!(res.attributes.contains(&Attribute::new(
+        "migrated_nft_info",
+        LEGACY_NFT_INFO_MIGRATION_LIMIT.to_string()
+    )));
+    assert!(res
+        .attributes
+        .contains(&Attribute::new("legacy_nft_info_cursor", last_token_id)));
+    // balances are backfilled along with NFTs
+    assert!(res.attributes.contains(&Attribute::new(
+        "migrated_balances",
+        LEGACY_NFT_INFO_MIGRATION_LIMIT.to_string()
+    )));
+    // executes are rejected until migration is completed, so legacy NFTs keep their data
+    let pending_token_id = format!("{:04}", LEGACY_NFT_INFO_MIGRATION_LIMIT);
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("venus"),
+            Cw721ExecuteMsg::TransferNft {
+                recipient: random.to_string(),
+                token_id: pending_token_id.clone(),
+            },
+        )
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::LegacyMigrationInProgress {});
+    // minting is rejected as well, so balances are not counted twice by backfill
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("minter"),
+            Cw721ExecuteMsg::Mint {
+                token_id: "fresh".to_string(),
+                owner: venus.to_string(),
+                token_uri: None,
+                extension: None,
+            },
+        )
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::LegacyMigrationInProgress {});
+    let res = contract
+        .migrate(
+            deps.as_mut(),
+            mock_env(),
+            migrate_msg.clone(),
+            "contract_name",
+            "contract_version",
+        )
+        .unwrap();
+    assert!(res
+        .attributes
+        .contains(&Attribute::new("migrated_nft_info", "1")));
+    assert!(res
+        .attributes
+        .contains(&Attribute::new("migrated_balances", "1")));
+    assert!(!res
+        .attributes
+        .iter()
+        .any(|attr| attr.key == "legacy_nft_info_cursor"));
+    let balance = contract
+        .query_balance_of(deps.as_ref(), venus.to_string())
+        .unwrap();
+    assert_eq!(balance.balance, LEGACY_NFT_INFO_MIGRATION_LIMIT as u64 + 1);
+    let nft_info = contract
+        .query_all_nft_info(deps.as_ref(), &mock_env(), pending_token_id.clone(), false)
+        .unwrap();
+    assert_eq!(nft_info.access.owner, venus.to_string());
+    assert_eq!(
+        nft_info.info.token_uri,
+        Some(format!("ipfs://legacy/{LEGACY_NFT_INFO_MIGRATION_LIMIT}"))
+    );
+    assert_eq!(
+        nft_info.access.approvals,
+        vec![Approval {
+            spender: random.clone(),
+            expires: Expiration::Never {},
+        }]
+    );
+    contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("venus"),
+            Cw721ExecuteMsg::TransferNft {
+                recipient: random.to_string(),
+                token_id: pending_token_id,
+            },
+        )
+        .unwrap();
+    // balances stay accurate after mint and transfer, and a later migration keeps them as is
+    contract
+        .execute(
+            deps.as_mut(),
+            &mock_env(),
+            &addrs.info("minter"),
+            Cw721ExecuteMsg::Mint {
+                token_id: "fresh".to_string(),
+                owner: random.to_string(),
+                token_uri: None,
+                extension: None,
+            },
+        )
+        .unwrap();
+    let res = contract
+        .migrate(
+            deps.as_mut(),
+            mock_env(),
+            migrate_msg,
+            "contract_name",
+            "contract_version",
+        )
+        .unwrap();
+    assert!(!res
+        .attributes
+        .iter()
+        .any(|attr| attr.key == "migrated_balances"));
+    let balance = contract
+        .query_balance_of(deps.as_ref(), venus.to_string())
+        .unwrap();
+    assert_eq!(balance.balance, LEGACY_NFT_INFO_MIGRATION_LIMIT as u64);
+    let balance = contract
+        .query_balance_of(deps.as_ref(), random.to_string())
+        .unwrap();
+    assert_eq!(balance.balance, 2);
+}
+
 #[test]
 fn test_set_withdraw_address() {
     let mut deps = mock_dependencies();
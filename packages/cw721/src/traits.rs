@@ -1,8 +1,9 @@
 use std::fmt::Debug;
 
 use cosmwasm_std::{
-    to_json_binary, Addr, Api, Binary, Coin, CosmosMsg, CustomMsg, Deps, DepsMut, Empty, Env,
-    MessageInfo, QuerierWrapper, Response, StdResult, Storage, WasmMsg, WasmQuery,
+    from_json, to_json_binary, Addr, Api, Binary, Coin, CosmosMsg, CustomMsg, Deps, DepsMut, Empty,
+    Env, HexBinary, MessageInfo, QuerierWrapper, Reply, Response, StdResult, Storage, Uint128,
+    WasmMsg, WasmQuery,
 };
 use cw_ownable::{Action, Ownership};
 use cw_utils::Expiration;
@@ -13,30 +14,56 @@ use serde::{de::DeserializeOwned, Serialize};
 use crate::{
     error::Cw721ContractError,
     execute::{
-        approve, approve_all, burn_nft, initialize_creator, initialize_minter, instantiate,
-        instantiate_with_version, migrate, mint, remove_withdraw_address, revoke, revoke_all,
-        send_nft, set_withdraw_address, transfer_nft, update_collection_info,
-        update_creator_ownership, update_minter_ownership, update_nft_info, withdraw_funds,
+        accept_transfer, add_filtered_operators, add_hook, approve, approve_all,
+        assert_no_legacy_migration, batch_burn_nft, batch_mint, batch_send_nft, batch_transfer_nft,
+        burn_nft, cancel_transfer_offer, claim, commit_reveal, freeze_all_nft_info,
+        freeze_base_uri, freeze_collection_info, freeze_nft_info, hook_submsgs, initialize_creator,
+        initialize_minter, instantiate, instantiate_with_version, lock, migrate, mint, move_nft,
+        offer_transfer, pause, permit_approve, permit_transfer, redeem_voucher,
+        remove_filtered_operators, remove_hook, remove_withdraw_address, reply, reveal, revoke,
+        revoke_all, seal_supply, send_nft, set_base_uri, set_merkle_root, set_operator_filter_mode,
+        set_user, set_voucher_signer, set_withdraw_address, unlock, unpause,
+        update_collection_info, update_creator_ownership, update_minter_ownership, update_nft_info,
+        update_pauser_ownership, withdraw_funds,
     },
     msg::{
-        AllNftInfoResponse, ApprovalResponse, ApprovalsResponse,
-        CollectionInfoAndExtensionResponse, CollectionInfoMsg, Cw721ExecuteMsg,
-        Cw721InstantiateMsg, Cw721MigrateMsg, Cw721QueryMsg, MinterResponse, NftInfoResponse,
-        NumTokensResponse, OperatorResponse, OperatorsResponse, OwnerOfResponse, TokensResponse,
+        AllNftInfoResponse, ApprovalResponse, ApprovalsResponse, BalanceOfResponse,
+        BatchMintResponse, BurnedTokensResponse, CollectionInfoAndExtensionResponse,
+        CollectionInfoMsg, Cw721ExecuteMsg, Cw721InstantiateMsg, Cw721MigrateMsg, Cw721QueryMsg,
+        FreezeStatusResponse, HooksResponse, IsBurnedResponse, IsClaimedResponse,
+        IsOperatorAllowedResponse, IsVoucherRedeemedResponse, LockStatusResponse,
+        MerkleRootResponse, MintMsg, MintResponse, MintVoucher, MinterResponse, NftInfoResponse,
+        NumTokensResponse, OperatorFilterResponse, OperatorResponse, OperatorsResponse,
+        OwnerOfAtHeightResponse, OwnerOfResponse, Permit, PermitNonceResponse,
+        RevealStatusResponse, TokenHistoryResponse, TokensResponse, TransferOfferResponse,
+        TransferOffersResponse, UserOfResponse, VoucherSignerResponse,
     },
     query::{
         query_all_nft_info, query_all_tokens, query_approval, query_approvals,
+        query_balance_at_height, query_balance_of, query_base_uri, query_burned_tokens,
         query_collection_extension_attributes, query_collection_info,
-        query_collection_info_and_extension, query_creator_ownership, query_minter,
-        query_minter_ownership, query_nft_info, query_num_tokens, query_operator, query_operators,
-        query_owner_of, query_tokens, query_withdraw_address,
+        query_collection_info_and_extension, query_creator_ownership, query_freeze_status,
+        query_hooks, query_is_burned, query_is_claimed, query_is_operator_allowed,
+        query_is_voucher_redeemed, query_lock_status, query_locks_by_locker, query_merkle_root,
+        query_minter, query_minter_ownership, query_nft_info, query_num_tokens, query_operator,
+        query_operator_filter, query_operators, query_owner_of, query_owner_of_at_height,
+        query_pause_status, query_pauser_ownership, query_permit_nonce, query_reveal_status,
+        query_token_history, query_tokens, query_total_supply_at_height, query_transfer_offer,
+        query_transfer_offers_by_recipient, query_user_of, query_voucher_signer,
+        query_withdraw_address,
+    },
+    state::{
+        BaseUri, CollectionInfo, Cw721Config, HookOnError, OperatorFilterMode, PauseScope,
+        PauseStatus, TokenHistoryAction,
     },
-    state::CollectionInfo,
     Attribute,
 };
 use crate::{
-    msg::{AllInfoResponse, ConfigResponse},
-    query::{query_all_info, query_config, query_nft_by_extension},
+    msg::{AllInfoResponse, CheckRoyaltiesResponse, ConfigResponse, RoyaltiesInfoResponse},
+    query::{
+        query_all_info, query_check_royalties, query_config, query_nft_by_extension,
+        query_royalty_info,
+    },
     Approval,
 };
 
@@ -136,6 +163,42 @@ where
     }
 }
 
+/// Reads the mint response set as data by mint functions, holding the (possibly generated) token ids.
+fn minted<T: DeserializeOwned, TCustomResponseMsg>(
+    res: &Response<TCustomResponseMsg>,
+) -> StdResult<T> {
+    from_json(res.data.as_ref().unwrap_or(&Binary::default()))
+}
+
+/// Info of the NFT owner signing a permit, passed to hooks instead of the relayer's info.
+fn permit_signer_info(storage: &dyn Storage, permit: &Permit) -> StdResult<MessageInfo> {
+    let config = Cw721Config::<Option<Empty>>::default();
+    let owner = config.nft_owner_info.load(storage, &permit.token_id)?.owner;
+    Ok(MessageInfo {
+        sender: owner,
+        funds: vec![],
+    })
+}
+
+/// Info of the transfer offerer and the offer's recipient, passed to hooks on accepting an offer.
+fn transfer_offer_info(
+    storage: &dyn Storage,
+    token_id: &str,
+) -> Result<(MessageInfo, String), Cw721ContractError> {
+    let config = Cw721Config::<Option<Empty>>::default();
+    let offer = config
+        .transfer_offers
+        .may_load(storage, token_id)?
+        .ok_or_else(|| Cw721ContractError::NoTransferOffer {
+            token_id: token_id.to_string(),
+        })?;
+    let offerer_info = MessageInfo {
+        sender: offer.offered_by,
+        funds: vec![],
+    };
+    Ok((offerer_info, offer.recipient.to_string()))
+}
+
 /// Trait with generic onchain nft and collection extensions used to execute the contract logic and contains default implementations for all messages.
 pub trait Cw721Execute<
     // NftInfo extension (onchain metadata).
@@ -186,6 +249,7 @@ pub trait Cw721Execute<
         info: &MessageInfo,
         msg: Cw721ExecuteMsg<TNftExtensionMsg, TCollectionExtensionMsg, TExtensionMsg>,
     ) -> Result<Response<TCustomResponseMsg>, Cw721ContractError> {
+        assert_no_legacy_migration(deps.storage)?;
         match msg {
             Cw721ExecuteMsg::UpdateCollectionInfo { collection_info } => {
                 self.update_collection_info(deps, info.into(), env, collection_info)
@@ -208,6 +272,56 @@ pub trait Cw721Execute<
                 self.approve_all(deps, env, info, operator, expires)
             }
             Cw721ExecuteMsg::RevokeAll { operator } => self.revoke_all(deps, env, info, operator),
+            Cw721ExecuteMsg::SetUser {
+                token_id,
+                user,
+                expires,
+            } => self.set_user(deps, env, info, token_id, user, expires),
+            Cw721ExecuteMsg::Lock { token_id } => self.lock(deps, env, info, token_id),
+            Cw721ExecuteMsg::Unlock { token_id } => self.unlock(deps, env, info, token_id),
+            Cw721ExecuteMsg::OfferTransfer {
+                token_id,
+                recipient,
+                expires,
+            } => self.offer_transfer(deps, env, info, token_id, recipient, expires),
+            Cw721ExecuteMsg::AcceptTransfer { token_id } => {
+                self.accept_transfer(deps, env, info, token_id)
+            }
+            Cw721ExecuteMsg::CancelTransferOffer { token_id } => {
+                self.cancel_transfer_offer(deps, env, info, token_id)
+            }
+            Cw721ExecuteMsg::PermitApprove {
+                permit,
+                pubkey,
+                signature,
+            } => self.permit_approve(deps, env, info, permit, pubkey, signature),
+            Cw721ExecuteMsg::PermitTransfer {
+                permit,
+                pubkey,
+                signature,
+            } => self.permit_transfer(deps, env, info, permit, pubkey, signature),
+            Cw721ExecuteMsg::SetVoucherSigner { pubkey } => {
+                self.set_voucher_signer(deps, env, info, pubkey)
+            }
+            Cw721ExecuteMsg::RedeemVoucher { voucher, signature } => {
+                self.redeem_voucher(deps, env, info, voucher, signature)
+            }
+            Cw721ExecuteMsg::AddHook {
+                contract_addr,
+                on_error,
+            } => self.add_hook(deps, env, info, contract_addr, on_error),
+            Cw721ExecuteMsg::RemoveHook { contract_addr } => {
+                self.remove_hook(deps, env, info, contract_addr)
+            }
+            Cw721ExecuteMsg::SetMerkleRoot { merkle_root } => {
+                self.set_merkle_root(deps, env, info, merkle_root)
+            }
+            Cw721ExecuteMsg::Claim {
+                token_id,
+                token_uri,
+                extension,
+                proof,
+            } => self.claim(deps, env, info, token_id, token_uri, extension, proof),
             Cw721ExecuteMsg::TransferNft {
                 recipient,
                 token_id,
@@ -218,6 +332,19 @@ pub trait Cw721Execute<
                 msg,
             } => self.send_nft(deps, env, info, contract, token_id, msg),
             Cw721ExecuteMsg::Burn { token_id } => self.burn_nft(deps, env, info, token_id),
+            Cw721ExecuteMsg::BatchMint { tokens } => self.batch_mint(deps, env, info, tokens),
+            Cw721ExecuteMsg::BatchTransferNft {
+                recipient,
+                token_ids,
+            } => self.batch_transfer_nft(deps, env, info, recipient, token_ids),
+            Cw721ExecuteMsg::BatchSendNft {
+                contract,
+                token_ids,
+                msg,
+            } => self.batch_send_nft(deps, env, info, contract, token_ids, msg),
+            Cw721ExecuteMsg::BatchBurn { token_ids } => {
+                self.batch_burn_nft(deps, env, info, token_ids)
+            }
             #[allow(deprecated)]
             Cw721ExecuteMsg::UpdateOwnership(action) => {
                 self.update_minter_ownership(deps, env, info, action)
@@ -228,6 +355,20 @@ pub trait Cw721Execute<
             Cw721ExecuteMsg::UpdateCreatorOwnership(action) => {
                 self.update_creator_ownership(deps, env, info, action)
             }
+            Cw721ExecuteMsg::UpdatePauserOwnership(action) => {
+                self.update_pauser_ownership(deps, env, info, action)
+            }
+            Cw721ExecuteMsg::SetOperatorFilterMode { mode } => {
+                self.set_operator_filter_mode(deps, env, info, mode)
+            }
+            Cw721ExecuteMsg::AddFilteredOperators { operators } => {
+                self.add_filtered_operators(deps, env, info, operators)
+            }
+            Cw721ExecuteMsg::RemoveFilteredOperators { operators } => {
+                self.remove_filtered_operators(deps, env, info, operators)
+            }
+            Cw721ExecuteMsg::Pause { scopes } => self.pause(deps, env, info, scopes),
+            Cw721ExecuteMsg::Unpause { scopes } => self.unpause(deps, env, info, scopes),
             #[allow(deprecated)]
             Cw721ExecuteMsg::UpdateExtension { msg } => {
                 self.execute_extension(deps, env, info, msg)
@@ -237,6 +378,25 @@ pub trait Cw721Execute<
                 token_uri,
                 extension,
             } => self.update_nft_info(deps, env, info, token_id, token_uri, extension),
+            Cw721ExecuteMsg::FreezeNftInfo { token_id } => {
+                self.freeze_nft_info(deps, env, info, token_id)
+            }
+            Cw721ExecuteMsg::FreezeAllNftInfo {} => self.freeze_all_nft_info(deps, env, info),
+            Cw721ExecuteMsg::FreezeCollectionInfo {} => {
+                self.freeze_collection_info(deps, env, info)
+            }
+            Cw721ExecuteMsg::SetBaseUri { base_uri } => {
+                self.set_base_uri(deps, env, info, base_uri)
+            }
+            Cw721ExecuteMsg::FreezeBaseUri {} => self.freeze_base_uri(deps, env, info),
+            Cw721ExecuteMsg::SealSupply {} => self.seal_supply(deps, env, info),
+            Cw721ExecuteMsg::CommitReveal {
+                commitment,
+                placeholder_uri,
+            } => self.commit_reveal(deps, env, info, commitment, placeholder_uri),
+            Cw721ExecuteMsg::Reveal { base_uri, salt } => {
+                self.reveal(deps, env, info, base_uri, salt)
+            }
             Cw721ExecuteMsg::SetWithdrawAddress { address } => {
                 self.set_withdraw_address(deps, &info.sender, address)
             }
@@ -255,37 +415,151 @@ pub trait Cw721Execute<
         contract_name: &str,
         contract_version: &str,
     ) -> Result<Response, Cw721ContractError> {
-        migrate(deps, env, msg, contract_name, contract_version)
+        migrate::<TNftExtension>(deps, env, msg, contract_name, contract_version)
+    }
+
+    fn reply(
+        &self,
+        deps: DepsMut,
+        env: &Env,
+        msg: Reply,
+    ) -> Result<Response<TCustomResponseMsg>, Cw721ContractError> {
+        reply::<TCustomResponseMsg>(deps, env, msg)
+    }
+
+    // ------- lifecycle hooks -------
+    // No-ops by default. Contracts override these to add custom checks or side effects, instead of
+    // re-implementing the `execute` dispatch.
+
+    /// Called before a token is transferred or sent to `recipient`. Returning an error aborts the transfer.
+    fn before_transfer(
+        &self,
+        _deps: Deps,
+        _env: &Env,
+        _info: &MessageInfo,
+        _recipient: &str,
+        _token_id: &str,
+    ) -> Result<(), Cw721ContractError> {
+        Ok(())
+    }
+
+    /// Called after a token has been transferred or sent to `recipient`, returns the (possibly extended) response.
+    fn after_transfer(
+        &self,
+        _deps: DepsMut,
+        _env: &Env,
+        _info: &MessageInfo,
+        _recipient: &str,
+        _token_id: &str,
+        response: Response<TCustomResponseMsg>,
+    ) -> Result<Response<TCustomResponseMsg>, Cw721ContractError> {
+        Ok(response)
+    }
+
+    /// Called before a token is minted for `owner`. Returning an error aborts the mint.
+    /// `token_id` is empty if it is generated by the configured token id strategy.
+    fn before_mint(
+        &self,
+        _deps: Deps,
+        _env: &Env,
+        _info: &MessageInfo,
+        _owner: &str,
+        _token_id: &str,
+    ) -> Result<(), Cw721ContractError> {
+        Ok(())
+    }
+
+    /// Called after a token has been minted for `owner`, returns the (possibly extended) response.
+    fn after_mint(
+        &self,
+        _deps: DepsMut,
+        _env: &Env,
+        _info: &MessageInfo,
+        _owner: &str,
+        _token_id: &str,
+        response: Response<TCustomResponseMsg>,
+    ) -> Result<Response<TCustomResponseMsg>, Cw721ContractError> {
+        Ok(response)
+    }
+
+    /// Called before a token is burned. Returning an error aborts the burn.
+    fn before_burn(
+        &self,
+        _deps: Deps,
+        _env: &Env,
+        _info: &MessageInfo,
+        _token_id: &str,
+    ) -> Result<(), Cw721ContractError> {
+        Ok(())
+    }
+
+    /// Called before `spender` is approved for a token, or as an operator for all tokens in which
+    /// case `token_id` is `None`. Returning an error aborts the approval.
+    fn before_approve(
+        &self,
+        _deps: Deps,
+        _env: &Env,
+        _info: &MessageInfo,
+        _spender: &str,
+        _token_id: Option<&str>,
+    ) -> Result<(), Cw721ContractError> {
+        Ok(())
     }
 
     // ------- ERC721-based functions -------
     fn transfer_nft(
         &self,
-        deps: DepsMut,
+        mut deps: DepsMut,
         env: &Env,
         info: &MessageInfo,
         recipient: String,
         token_id: String,
     ) -> Result<Response<TCustomResponseMsg>, Cw721ContractError> {
-        transfer_nft::<TNftExtension>(deps, env, info, &recipient, &token_id)?;
-
-        Ok(Response::new()
+        self.before_transfer(deps.as_ref(), env, info, &recipient, &token_id)?;
+        let (previous_owner, token) = move_nft(
+            deps.branch(),
+            env,
+            info,
+            &recipient,
+            &token_id,
+            TokenHistoryAction::Transfer,
+        )?;
+        let hooks = hook_submsgs(
+            deps.storage,
+            TokenHistoryAction::Transfer,
+            &token_id,
+            Some(&previous_owner),
+            Some(&token.owner),
+        )?;
+
+        let res = Response::new()
+            .add_submessages(hooks)
             .add_attribute("action", "transfer_nft")
             .add_attribute("sender", info.sender.to_string())
-            .add_attribute("recipient", recipient)
-            .add_attribute("token_id", token_id))
+            .add_attribute("recipient", recipient.clone())
+            .add_attribute("token_id", token_id.clone());
+        self.after_transfer(deps, env, info, &recipient, &token_id, res)
     }
 
     fn send_nft(
         &self,
-        deps: DepsMut,
+        mut deps: DepsMut,
         env: &Env,
         info: &MessageInfo,
         contract: String,
         token_id: String,
         msg: Binary,
     ) -> Result<Response<TCustomResponseMsg>, Cw721ContractError> {
-        send_nft::<TNftExtension, TCustomResponseMsg>(deps, env, info, contract, token_id, msg)
+        self.before_transfer(deps.as_ref(), env, info, &contract, &token_id)?;
+        let res = send_nft::<TCustomResponseMsg>(
+            deps.branch(),
+            env,
+            info,
+            contract.clone(),
+            token_id.clone(),
+            msg,
+        )?;
+        self.after_transfer(deps, env, info, &contract, &token_id, res)
     }
 
     fn approve(
@@ -297,148 +571,584 @@ pub trait Cw721Execute<
         token_id: String,
         expires: Option<Expiration>,
     ) -> Result<Response<TCustomResponseMsg>, Cw721ContractError> {
-        approve::<TNftExtension, TCustomResponseMsg>(deps, env, info, spender, token_id, expires)
+        self.before_approve(deps.as_ref(), env, info, &spender, Some(&token_id))?;
+        approve::<TCustomResponseMsg>(deps, env, info, spender, token_id, expires)
+    }
+
+    fn revoke(
+        &self,
+        deps: DepsMut,
+        env: &Env,
+        info: &MessageInfo,
+        spender: String,
+        token_id: String,
+    ) -> Result<Response<TCustomResponseMsg>, Cw721ContractError> {
+        revoke::<TCustomResponseMsg>(deps, env, info, spender, token_id)
+    }
+
+    fn approve_all(
+        &self,
+        deps: DepsMut,
+        env: &Env,
+        info: &MessageInfo,
+        operator: String,
+        expires: Option<Expiration>,
+    ) -> Result<Response<TCustomResponseMsg>, Cw721ContractError> {
+        self.before_approve(deps.as_ref(), env, info, &operator, None)?;
+        approve_all::<TCustomResponseMsg>(deps, env, info, operator, expires)
+    }
+
+    fn revoke_all(
+        &self,
+        deps: DepsMut,
+        _env: &Env,
+        info: &MessageInfo,
+        operator: String,
+    ) -> Result<Response<TCustomResponseMsg>, Cw721ContractError> {
+        revoke_all::<TCustomResponseMsg>(deps, _env, info, operator)
+    }
+
+    fn set_user(
+        &self,
+        deps: DepsMut,
+        env: &Env,
+        info: &MessageInfo,
+        token_id: String,
+        user: Option<String>,
+        expires: Option<Expiration>,
+    ) -> Result<Response<TCustomResponseMsg>, Cw721ContractError> {
+        set_user::<TCustomResponseMsg>(deps, env, info, token_id, user, expires)
+    }
+
+    fn lock(
+        &self,
+        deps: DepsMut,
+        env: &Env,
+        info: &MessageInfo,
+        token_id: String,
+    ) -> Result<Response<TCustomResponseMsg>, Cw721ContractError> {
+        lock::<TCustomResponseMsg>(deps, env, info, token_id)
+    }
+
+    fn unlock(
+        &self,
+        deps: DepsMut,
+        env: &Env,
+        info: &MessageInfo,
+        token_id: String,
+    ) -> Result<Response<TCustomResponseMsg>, Cw721ContractError> {
+        unlock::<TCustomResponseMsg>(deps, env, info, token_id)
+    }
+
+    fn offer_transfer(
+        &self,
+        deps: DepsMut,
+        env: &Env,
+        info: &MessageInfo,
+        token_id: String,
+        recipient: String,
+        expires: Option<Expiration>,
+    ) -> Result<Response<TCustomResponseMsg>, Cw721ContractError> {
+        offer_transfer::<TCustomResponseMsg>(deps, env, info, token_id, recipient, expires)
+    }
+
+    fn accept_transfer(
+        &self,
+        mut deps: DepsMut,
+        env: &Env,
+        info: &MessageInfo,
+        token_id: String,
+    ) -> Result<Response<TCustomResponseMsg>, Cw721ContractError> {
+        let (offerer_info, recipient) = transfer_offer_info(deps.storage, &token_id)?;
+        self.before_transfer(deps.as_ref(), env, &offerer_info, &recipient, &token_id)?;
+        let res =
+            accept_transfer::<TCustomResponseMsg>(deps.branch(), env, info, token_id.clone())?;
+        self.after_transfer(deps, env, &offerer_info, &recipient, &token_id, res)
+    }
+
+    fn cancel_transfer_offer(
+        &self,
+        deps: DepsMut,
+        env: &Env,
+        info: &MessageInfo,
+        token_id: String,
+    ) -> Result<Response<TCustomResponseMsg>, Cw721ContractError> {
+        cancel_transfer_offer::<TCustomResponseMsg>(deps, env, info, token_id)
+    }
+
+    fn permit_approve(
+        &self,
+        deps: DepsMut,
+        env: &Env,
+        info: &MessageInfo,
+        permit: Permit,
+        pubkey: Binary,
+        signature: Binary,
+    ) -> Result<Response<TCustomResponseMsg>, Cw721ContractError> {
+        let signer_info = permit_signer_info(deps.storage, &permit)?;
+        self.before_approve(
+            deps.as_ref(),
+            env,
+            &signer_info,
+            &permit.spender,
+            Some(&permit.token_id),
+        )?;
+        permit_approve::<TCustomResponseMsg>(deps, env, info, permit, pubkey, signature)
+    }
+
+    fn permit_transfer(
+        &self,
+        mut deps: DepsMut,
+        env: &Env,
+        info: &MessageInfo,
+        permit: Permit,
+        pubkey: Binary,
+        signature: Binary,
+    ) -> Result<Response<TCustomResponseMsg>, Cw721ContractError> {
+        let (recipient, token_id) = (permit.spender.clone(), permit.token_id.clone());
+        let signer_info = permit_signer_info(deps.storage, &permit)?;
+        self.before_transfer(deps.as_ref(), env, &signer_info, &recipient, &token_id)?;
+        let res = permit_transfer::<TCustomResponseMsg>(
+            deps.branch(),
+            env,
+            info,
+            permit,
+            pubkey,
+            signature,
+        )?;
+        self.after_transfer(deps, env, &signer_info, &recipient, &token_id, res)
+    }
+
+    fn set_voucher_signer(
+        &self,
+        deps: DepsMut,
+        env: &Env,
+        info: &MessageInfo,
+        pubkey: Option<Binary>,
+    ) -> Result<Response<TCustomResponseMsg>, Cw721ContractError> {
+        set_voucher_signer::<TCustomResponseMsg>(deps, env, info, pubkey)
+    }
+
+    fn redeem_voucher(
+        &self,
+        mut deps: DepsMut,
+        env: &Env,
+        info: &MessageInfo,
+        voucher: MintVoucher<TNftExtensionMsg>,
+        signature: Binary,
+    ) -> Result<Response<TCustomResponseMsg>, Cw721ContractError> {
+        let owner = info.sender.to_string();
+        self.before_mint(deps.as_ref(), env, info, &owner, &voucher.token_id)?;
+        let res = redeem_voucher::<TNftExtension, TNftExtensionMsg, TCustomResponseMsg>(
+            deps.branch(),
+            env,
+            info,
+            voucher,
+            signature,
+        )?;
+        let MintResponse { token_id } = minted(&res)?;
+        self.after_mint(deps, env, info, &owner, &token_id, res)
+    }
+
+    fn add_hook(
+        &self,
+        deps: DepsMut,
+        env: &Env,
+        info: &MessageInfo,
+        contract_addr: String,
+        on_error: HookOnError,
+    ) -> Result<Response<TCustomResponseMsg>, Cw721ContractError> {
+        add_hook::<TCustomResponseMsg>(deps, env, info, contract_addr, on_error)
+    }
+
+    fn remove_hook(
+        &self,
+        deps: DepsMut,
+        env: &Env,
+        info: &MessageInfo,
+        contract_addr: String,
+    ) -> Result<Response<TCustomResponseMsg>, Cw721ContractError> {
+        remove_hook::<TCustomResponseMsg>(deps, env, info, contract_addr)
+    }
+
+    fn set_merkle_root(
+        &self,
+        deps: DepsMut,
+        env: &Env,
+        info: &MessageInfo,
+        merkle_root: Option<HexBinary>,
+    ) -> Result<Response<TCustomResponseMsg>, Cw721ContractError> {
+        set_merkle_root::<TCustomResponseMsg>(deps, env, info, merkle_root)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn claim(
+        &self,
+        mut deps: DepsMut,
+        env: &Env,
+        info: &MessageInfo,
+        token_id: String,
+        token_uri: Option<String>,
+        extension: TNftExtensionMsg,
+        proof: Vec<HexBinary>,
+    ) -> Result<Response<TCustomResponseMsg>, Cw721ContractError> {
+        let owner = info.sender.to_string();
+        self.before_mint(deps.as_ref(), env, info, &owner, &token_id)?;
+        let res = claim::<TNftExtension, TNftExtensionMsg, TCustomResponseMsg>(
+            deps.branch(),
+            env,
+            info,
+            token_id,
+            token_uri,
+            extension,
+            proof,
+        )?;
+        let MintResponse { token_id } = minted(&res)?;
+        self.after_mint(deps, env, info, &owner, &token_id, res)
+    }
+
+    fn burn_nft(
+        &self,
+        deps: DepsMut,
+        env: &Env,
+        info: &MessageInfo,
+        token_id: String,
+    ) -> Result<Response<TCustomResponseMsg>, Cw721ContractError> {
+        self.before_burn(deps.as_ref(), env, info, &token_id)?;
+        burn_nft::<TCustomResponseMsg>(deps, env, info, token_id)
+    }
+
+    fn batch_transfer_nft(
+        &self,
+        mut deps: DepsMut,
+        env: &Env,
+        info: &MessageInfo,
+        recipient: String,
+        token_ids: Vec<String>,
+    ) -> Result<Response<TCustomResponseMsg>, Cw721ContractError> {
+        for token_id in &token_ids {
+            self.before_transfer(deps.as_ref(), env, info, &recipient, token_id)?;
+        }
+        let mut res = batch_transfer_nft::<TCustomResponseMsg>(
+            deps.branch(),
+            env,
+            info,
+            recipient.clone(),
+            token_ids.clone(),
+        )?;
+        for token_id in &token_ids {
+            res = self.after_transfer(deps.branch(), env, info, &recipient, token_id, res)?;
+        }
+        Ok(res)
+    }
+
+    fn batch_send_nft(
+        &self,
+        mut deps: DepsMut,
+        env: &Env,
+        info: &MessageInfo,
+        contract: String,
+        token_ids: Vec<String>,
+        msg: Binary,
+    ) -> Result<Response<TCustomResponseMsg>, Cw721ContractError> {
+        for token_id in &token_ids {
+            self.before_transfer(deps.as_ref(), env, info, &contract, token_id)?;
+        }
+        let mut res = batch_send_nft::<TCustomResponseMsg>(
+            deps.branch(),
+            env,
+            info,
+            contract.clone(),
+            token_ids.clone(),
+            msg,
+        )?;
+        for token_id in &token_ids {
+            res = self.after_transfer(deps.branch(), env, info, &contract, token_id, res)?;
+        }
+        Ok(res)
+    }
+
+    fn batch_burn_nft(
+        &self,
+        deps: DepsMut,
+        env: &Env,
+        info: &MessageInfo,
+        token_ids: Vec<String>,
+    ) -> Result<Response<TCustomResponseMsg>, Cw721ContractError> {
+        for token_id in &token_ids {
+            self.before_burn(deps.as_ref(), env, info, token_id)?;
+        }
+        batch_burn_nft::<TCustomResponseMsg>(deps, env, info, token_ids)
+    }
+
+    // ------- opionated cw721 functions -------
+    fn initialize_creator(
+        &self,
+        storage: &mut dyn Storage,
+        api: &dyn Api,
+        creator: Option<&str>,
+    ) -> StdResult<Ownership<Addr>> {
+        initialize_creator(storage, api, creator)
+    }
+
+    fn initialize_minter(
+        &self,
+        storage: &mut dyn Storage,
+        api: &dyn Api,
+        minter: Option<&str>,
+    ) -> StdResult<Ownership<Addr>> {
+        initialize_minter(storage, api, minter)
+    }
+
+    fn update_collection_info(
+        &self,
+        deps: DepsMut,
+        info: Option<&MessageInfo>,
+        env: &Env,
+        msg: CollectionInfoMsg<TCollectionExtensionMsg>,
+    ) -> Result<Response<TCustomResponseMsg>, Cw721ContractError> {
+        update_collection_info::<TCollectionExtension, TCollectionExtensionMsg, TCustomResponseMsg>(
+            deps, info, env, msg,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn mint(
+        &self,
+        mut deps: DepsMut,
+        env: &Env,
+        info: &MessageInfo,
+        token_id: String,
+        owner: String,
+        token_uri: Option<String>,
+        extension: TNftExtensionMsg,
+    ) -> Result<Response<TCustomResponseMsg>, Cw721ContractError> {
+        self.before_mint(deps.as_ref(), env, info, &owner, &token_id)?;
+        let res = mint::<TNftExtension, TNftExtensionMsg, TCustomResponseMsg>(
+            deps.branch(),
+            env,
+            info,
+            token_id,
+            owner.clone(),
+            token_uri,
+            extension,
+        )?;
+        let MintResponse { token_id } = minted(&res)?;
+        self.after_mint(deps, env, info, &owner, &token_id, res)
+    }
+
+    fn batch_mint(
+        &self,
+        mut deps: DepsMut,
+        env: &Env,
+        info: &MessageInfo,
+        tokens: Vec<MintMsg<TNftExtensionMsg>>,
+    ) -> Result<Response<TCustomResponseMsg>, Cw721ContractError> {
+        for token in &tokens {
+            self.before_mint(deps.as_ref(), env, info, &token.owner, &token.token_id)?;
+        }
+        let owners: Vec<String> = tokens.iter().map(|token| token.owner.clone()).collect();
+        let mut res = batch_mint::<TNftExtension, TNftExtensionMsg, TCustomResponseMsg>(
+            deps.branch(),
+            env,
+            info,
+            tokens,
+        )?;
+        let BatchMintResponse { token_ids } = minted(&res)?;
+        for (owner, token_id) in owners.iter().zip(token_ids) {
+            res = self.after_mint(deps.branch(), env, info, owner, &token_id, res)?;
+        }
+        Ok(res)
+    }
+
+    fn update_minter_ownership(
+        &self,
+        deps: DepsMut,
+        env: &Env,
+        info: &MessageInfo,
+        action: Action,
+    ) -> Result<Response<TCustomResponseMsg>, Cw721ContractError> {
+        update_minter_ownership::<TCustomResponseMsg>(deps, env, info, action)
+    }
+
+    fn update_creator_ownership(
+        &self,
+        deps: DepsMut,
+        env: &Env,
+        info: &MessageInfo,
+        action: Action,
+    ) -> Result<Response<TCustomResponseMsg>, Cw721ContractError> {
+        update_creator_ownership::<TCustomResponseMsg>(deps, env, info, action)
+    }
+
+    fn update_pauser_ownership(
+        &self,
+        deps: DepsMut,
+        env: &Env,
+        info: &MessageInfo,
+        action: Action,
+    ) -> Result<Response<TCustomResponseMsg>, Cw721ContractError> {
+        update_pauser_ownership::<TCustomResponseMsg>(deps, env, info, action)
+    }
+
+    fn set_operator_filter_mode(
+        &self,
+        deps: DepsMut,
+        env: &Env,
+        info: &MessageInfo,
+        mode: Option<OperatorFilterMode>,
+    ) -> Result<Response<TCustomResponseMsg>, Cw721ContractError> {
+        set_operator_filter_mode::<TCustomResponseMsg>(deps, env, info, mode)
+    }
+
+    fn add_filtered_operators(
+        &self,
+        deps: DepsMut,
+        env: &Env,
+        info: &MessageInfo,
+        operators: Vec<String>,
+    ) -> Result<Response<TCustomResponseMsg>, Cw721ContractError> {
+        add_filtered_operators::<TCustomResponseMsg>(deps, env, info, operators)
+    }
+
+    fn remove_filtered_operators(
+        &self,
+        deps: DepsMut,
+        env: &Env,
+        info: &MessageInfo,
+        operators: Vec<String>,
+    ) -> Result<Response<TCustomResponseMsg>, Cw721ContractError> {
+        remove_filtered_operators::<TCustomResponseMsg>(deps, env, info, operators)
+    }
+
+    fn pause(
+        &self,
+        deps: DepsMut,
+        env: &Env,
+        info: &MessageInfo,
+        scopes: Vec<PauseScope>,
+    ) -> Result<Response<TCustomResponseMsg>, Cw721ContractError> {
+        pause::<TCustomResponseMsg>(deps, env, info, scopes)
+    }
+
+    fn unpause(
+        &self,
+        deps: DepsMut,
+        env: &Env,
+        info: &MessageInfo,
+        scopes: Vec<PauseScope>,
+    ) -> Result<Response<TCustomResponseMsg>, Cw721ContractError> {
+        unpause::<TCustomResponseMsg>(deps, env, info, scopes)
+    }
+
+    /// Custom msg execution. This is a no-op in default implementation.
+    fn execute_extension(
+        &self,
+        _deps: DepsMut,
+        _env: &Env,
+        _info: &MessageInfo,
+        _msg: TExtensionMsg,
+    ) -> Result<Response<TCustomResponseMsg>, Cw721ContractError> {
+        Ok(Response::default())
     }
 
-    fn revoke(
+    /// The creator is the only one eligible to update NFT's token uri and onchain metadata (`NftInfo.extension`).
+    /// NOTE: approvals and owner are not affected by this call, since they belong to the NFT owner.
+    fn update_nft_info(
         &self,
         deps: DepsMut,
         env: &Env,
         info: &MessageInfo,
-        spender: String,
         token_id: String,
+        token_uri: Option<String>,
+        msg: TNftExtensionMsg,
     ) -> Result<Response<TCustomResponseMsg>, Cw721ContractError> {
-        revoke::<TNftExtension, TCustomResponseMsg>(deps, env, info, spender, token_id)
+        update_nft_info::<TNftExtension, TNftExtensionMsg, TCustomResponseMsg>(
+            deps,
+            env,
+            info.into(),
+            token_id,
+            token_uri,
+            msg,
+        )
     }
 
-    fn approve_all(
+    fn freeze_nft_info(
         &self,
         deps: DepsMut,
         env: &Env,
         info: &MessageInfo,
-        operator: String,
-        expires: Option<Expiration>,
+        token_id: String,
     ) -> Result<Response<TCustomResponseMsg>, Cw721ContractError> {
-        approve_all::<TCustomResponseMsg>(deps, env, info, operator, expires)
+        freeze_nft_info::<TNftExtension, TCustomResponseMsg>(deps, env, info, token_id)
     }
 
-    fn revoke_all(
+    fn freeze_all_nft_info(
         &self,
         deps: DepsMut,
-        _env: &Env,
+        env: &Env,
         info: &MessageInfo,
-        operator: String,
     ) -> Result<Response<TCustomResponseMsg>, Cw721ContractError> {
-        revoke_all::<TCustomResponseMsg>(deps, _env, info, operator)
+        freeze_all_nft_info::<TCustomResponseMsg>(deps, env, info)
     }
 
-    fn burn_nft(
+    fn freeze_collection_info(
         &self,
         deps: DepsMut,
         env: &Env,
         info: &MessageInfo,
-        token_id: String,
     ) -> Result<Response<TCustomResponseMsg>, Cw721ContractError> {
-        burn_nft::<TCustomResponseMsg>(deps, env, info, token_id)
-    }
-
-    // ------- opionated cw721 functions -------
-    fn initialize_creator(
-        &self,
-        storage: &mut dyn Storage,
-        api: &dyn Api,
-        creator: Option<&str>,
-    ) -> StdResult<Ownership<Addr>> {
-        initialize_creator(storage, api, creator)
-    }
-
-    fn initialize_minter(
-        &self,
-        storage: &mut dyn Storage,
-        api: &dyn Api,
-        minter: Option<&str>,
-    ) -> StdResult<Ownership<Addr>> {
-        initialize_minter(storage, api, minter)
+        freeze_collection_info::<TCustomResponseMsg>(deps, env, info)
     }
 
-    fn update_collection_info(
+    fn set_base_uri(
         &self,
         deps: DepsMut,
-        info: Option<&MessageInfo>,
         env: &Env,
-        msg: CollectionInfoMsg<TCollectionExtensionMsg>,
+        info: &MessageInfo,
+        base_uri: Option<BaseUri>,
     ) -> Result<Response<TCustomResponseMsg>, Cw721ContractError> {
-        update_collection_info::<TCollectionExtension, TCollectionExtensionMsg, TCustomResponseMsg>(
-            deps, info, env, msg,
-        )
+        set_base_uri::<TCustomResponseMsg>(deps, env, info, base_uri)
     }
 
-    #[allow(clippy::too_many_arguments)]
-    fn mint(
+    fn freeze_base_uri(
         &self,
         deps: DepsMut,
         env: &Env,
         info: &MessageInfo,
-        token_id: String,
-        owner: String,
-        token_uri: Option<String>,
-        extension: TNftExtensionMsg,
     ) -> Result<Response<TCustomResponseMsg>, Cw721ContractError> {
-        mint::<TNftExtension, TNftExtensionMsg, TCustomResponseMsg>(
-            deps, env, info, token_id, owner, token_uri, extension,
-        )
+        freeze_base_uri::<TCustomResponseMsg>(deps, env, info)
     }
 
-    fn update_minter_ownership(
+    fn seal_supply(
         &self,
         deps: DepsMut,
         env: &Env,
         info: &MessageInfo,
-        action: Action,
     ) -> Result<Response<TCustomResponseMsg>, Cw721ContractError> {
-        update_minter_ownership::<TCustomResponseMsg>(deps, env, info, action)
+        seal_supply::<TCustomResponseMsg>(deps, env, info)
     }
 
-    fn update_creator_ownership(
+    fn commit_reveal(
         &self,
         deps: DepsMut,
         env: &Env,
         info: &MessageInfo,
-        action: Action,
-    ) -> Result<Response<TCustomResponseMsg>, Cw721ContractError> {
-        update_creator_ownership::<TCustomResponseMsg>(deps, env, info, action)
-    }
-
-    /// Custom msg execution. This is a no-op in default implementation.
-    fn execute_extension(
-        &self,
-        _deps: DepsMut,
-        _env: &Env,
-        _info: &MessageInfo,
-        _msg: TExtensionMsg,
+        commitment: HexBinary,
+        placeholder_uri: String,
     ) -> Result<Response<TCustomResponseMsg>, Cw721ContractError> {
-        Ok(Response::default())
+        commit_reveal::<TCustomResponseMsg>(deps, env, info, commitment, placeholder_uri)
     }
 
-    /// The creator is the only one eligible to update NFT's token uri and onchain metadata (`NftInfo.extension`).
-    /// NOTE: approvals and owner are not affected by this call, since they belong to the NFT owner.
-    fn update_nft_info(
+    fn reveal(
         &self,
         deps: DepsMut,
         env: &Env,
         info: &MessageInfo,
-        token_id: String,
-        token_uri: Option<String>,
-        msg: TNftExtensionMsg,
+        base_uri: String,
+        salt: String,
     ) -> Result<Response<TCustomResponseMsg>, Cw721ContractError> {
-        update_nft_info::<TNftExtension, TNftExtensionMsg, TCustomResponseMsg>(
-            deps,
-            env,
-            info.into(),
-            token_id,
-            token_uri,
-            msg,
-        )
+        reveal::<TCustomResponseMsg>(deps, env, info, base_uri, salt)
     }
 
     fn set_withdraw_address(
@@ -525,6 +1235,9 @@ pub trait Cw721Query<
                 token_id,
                 include_expired.unwrap_or(false),
             )?)?),
+            Cw721QueryMsg::UserOf { token_id } => {
+                Ok(to_json_binary(&self.query_user_of(deps, env, token_id)?)?)
+            }
             Cw721QueryMsg::AllNftInfo {
                 token_id,
                 include_expired,
@@ -561,6 +1274,18 @@ pub trait Cw721Query<
             Cw721QueryMsg::NumTokens {} => {
                 Ok(to_json_binary(&self.query_num_tokens(deps.storage)?)?)
             }
+            Cw721QueryMsg::BalanceOf { owner } => {
+                Ok(to_json_binary(&self.query_balance_of(deps, owner)?)?)
+            }
+            Cw721QueryMsg::OwnerOfAtHeight { token_id, height } => Ok(to_json_binary(
+                &self.query_owner_of_at_height(deps, token_id, height)?,
+            )?),
+            Cw721QueryMsg::BalanceAtHeight { owner, height } => Ok(to_json_binary(
+                &self.query_balance_at_height(deps, owner, height)?,
+            )?),
+            Cw721QueryMsg::TotalSupplyAtHeight { height } => Ok(to_json_binary(
+                &self.query_total_supply_at_height(deps, height)?,
+            )?),
             Cw721QueryMsg::Tokens {
                 owner,
                 start_after,
@@ -575,6 +1300,66 @@ pub trait Cw721Query<
             Cw721QueryMsg::AllTokens { start_after, limit } => Ok(to_json_binary(
                 &self.query_all_tokens(deps, env, start_after, limit)?,
             )?),
+            Cw721QueryMsg::LockStatus { token_id } => {
+                Ok(to_json_binary(&self.query_lock_status(deps, token_id)?)?)
+            }
+            Cw721QueryMsg::LocksByLocker {
+                locker,
+                start_after,
+                limit,
+            } => Ok(to_json_binary(&self.query_locks_by_locker(
+                deps,
+                locker,
+                start_after,
+                limit,
+            )?)?),
+            Cw721QueryMsg::TransferOffer { token_id } => {
+                Ok(to_json_binary(&self.query_transfer_offer(deps, token_id)?)?)
+            }
+            Cw721QueryMsg::PermitNonce { owner } => {
+                Ok(to_json_binary(&self.query_permit_nonce(deps, owner)?)?)
+            }
+            Cw721QueryMsg::VoucherSigner {} => {
+                Ok(to_json_binary(&self.query_voucher_signer(deps)?)?)
+            }
+            Cw721QueryMsg::IsVoucherRedeemed { voucher_hash } => Ok(to_json_binary(
+                &self.query_is_voucher_redeemed(deps, voucher_hash)?,
+            )?),
+            Cw721QueryMsg::Hooks { start_after, limit } => Ok(to_json_binary(&self.query_hooks(
+                deps,
+                start_after,
+                limit,
+            )?)?),
+            Cw721QueryMsg::MerkleRoot {} => Ok(to_json_binary(&self.query_merkle_root(deps)?)?),
+            Cw721QueryMsg::IsClaimed { leaf } => {
+                Ok(to_json_binary(&self.query_is_claimed(deps, leaf)?)?)
+            }
+            Cw721QueryMsg::TransferOffersByRecipient {
+                recipient,
+                start_after,
+                limit,
+            } => Ok(to_json_binary(&self.query_transfer_offers_by_recipient(
+                deps,
+                recipient,
+                start_after,
+                limit,
+            )?)?),
+            Cw721QueryMsg::TokenHistory {
+                token_id,
+                start_after,
+                limit,
+            } => Ok(to_json_binary(&self.query_token_history(
+                deps,
+                token_id,
+                start_after,
+                limit,
+            )?)?),
+            Cw721QueryMsg::BurnedTokens { start_after, limit } => Ok(to_json_binary(
+                &self.query_burned_tokens(deps, start_after, limit)?,
+            )?),
+            Cw721QueryMsg::IsBurned { token_id } => {
+                Ok(to_json_binary(&self.query_is_burned(deps, token_id)?)?)
+            }
             Cw721QueryMsg::Approval {
                 token_id,
                 spender,
@@ -605,6 +1390,23 @@ pub trait Cw721Query<
             Cw721QueryMsg::GetCreatorOwnership {} => Ok(to_json_binary(
                 &self.query_creator_ownership(deps.storage)?,
             )?),
+            Cw721QueryMsg::GetPauserOwnership {} => {
+                Ok(to_json_binary(&self.query_pauser_ownership(deps.storage)?)?)
+            }
+            Cw721QueryMsg::OperatorFilter { start_after, limit } => Ok(to_json_binary(
+                &self.query_operator_filter(deps, start_after, limit)?,
+            )?),
+            Cw721QueryMsg::IsOperatorAllowed { operator } => Ok(to_json_binary(
+                &self.query_is_operator_allowed(deps, operator)?,
+            )?),
+            Cw721QueryMsg::BaseUri {} => Ok(to_json_binary(&self.query_base_uri(deps.storage)?)?),
+            Cw721QueryMsg::RevealStatus {} => Ok(to_json_binary(&self.query_reveal_status(deps)?)?),
+            Cw721QueryMsg::FreezeStatus { token_id } => {
+                Ok(to_json_binary(&self.query_freeze_status(deps, token_id)?)?)
+            }
+            Cw721QueryMsg::PauseStatus {} => {
+                Ok(to_json_binary(&self.query_pause_status(deps.storage)?)?)
+            }
             Cw721QueryMsg::Extension { msg } => self.query_extension(deps, env, msg),
             Cw721QueryMsg::GetCollectionExtension { msg } => {
                 self.query_custom_collection_extension(deps, env, msg)
@@ -612,6 +1414,15 @@ pub trait Cw721Query<
             Cw721QueryMsg::GetWithdrawAddress {} => {
                 Ok(to_json_binary(&self.query_withdraw_address(deps)?)?)
             }
+            Cw721QueryMsg::RoyaltyInfo {
+                token_id,
+                sale_price,
+            } => Ok(to_json_binary(
+                &self.query_royalty_info(deps, token_id, sale_price)?,
+            )?),
+            Cw721QueryMsg::CheckRoyalties {} => {
+                Ok(to_json_binary(&self.query_check_royalties(deps)?)?)
+            }
         }
     }
 
@@ -630,6 +1441,47 @@ pub trait Cw721Query<
         query_creator_ownership(storage)
     }
 
+    fn query_pauser_ownership(&self, storage: &dyn Storage) -> StdResult<Ownership<Addr>> {
+        query_pauser_ownership(storage)
+    }
+
+    fn query_operator_filter(
+        &self,
+        deps: Deps,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> StdResult<OperatorFilterResponse> {
+        query_operator_filter(deps, start_after, limit)
+    }
+
+    fn query_is_operator_allowed(
+        &self,
+        deps: Deps,
+        operator: String,
+    ) -> StdResult<IsOperatorAllowedResponse> {
+        query_is_operator_allowed(deps, operator)
+    }
+
+    fn query_base_uri(&self, storage: &dyn Storage) -> StdResult<Option<BaseUri>> {
+        query_base_uri(storage)
+    }
+
+    fn query_reveal_status(&self, deps: Deps) -> StdResult<RevealStatusResponse> {
+        query_reveal_status(deps)
+    }
+
+    fn query_freeze_status(
+        &self,
+        deps: Deps,
+        token_id: Option<String>,
+    ) -> StdResult<FreezeStatusResponse> {
+        query_freeze_status(deps, token_id)
+    }
+
+    fn query_pause_status(&self, storage: &dyn Storage) -> StdResult<PauseStatus> {
+        query_pause_status(storage)
+    }
+
     fn query_collection_info(&self, deps: Deps) -> StdResult<CollectionInfo> {
         query_collection_info(deps.storage)
     }
@@ -667,6 +1519,36 @@ pub trait Cw721Query<
         query_num_tokens(storage)
     }
 
+    fn query_balance_of(&self, deps: Deps, owner: String) -> StdResult<BalanceOfResponse> {
+        query_balance_of(deps, owner)
+    }
+
+    fn query_owner_of_at_height(
+        &self,
+        deps: Deps,
+        token_id: String,
+        height: u64,
+    ) -> StdResult<OwnerOfAtHeightResponse> {
+        query_owner_of_at_height(deps, token_id, height)
+    }
+
+    fn query_balance_at_height(
+        &self,
+        deps: Deps,
+        owner: String,
+        height: u64,
+    ) -> StdResult<BalanceOfResponse> {
+        query_balance_at_height(deps, owner, height)
+    }
+
+    fn query_total_supply_at_height(
+        &self,
+        deps: Deps,
+        height: u64,
+    ) -> StdResult<NumTokensResponse> {
+        query_total_supply_at_height(deps, height)
+    }
+
     fn query_nft_info(
         &self,
         storage: &dyn Storage,
@@ -695,6 +1577,10 @@ pub trait Cw721Query<
         query_owner_of(deps, env, token_id, include_expired_approval)
     }
 
+    fn query_user_of(&self, deps: Deps, env: &Env, token_id: String) -> StdResult<UserOfResponse> {
+        query_user_of(deps, env, token_id)
+    }
+
     /// operator returns the approval status of an operator for a given owner if exists
     fn query_operator(
         &self,
@@ -776,6 +1662,94 @@ pub trait Cw721Query<
         query_all_tokens(deps, _env, start_after, limit)
     }
 
+    fn query_lock_status(&self, deps: Deps, token_id: String) -> StdResult<LockStatusResponse> {
+        query_lock_status(deps, token_id)
+    }
+
+    fn query_locks_by_locker(
+        &self,
+        deps: Deps,
+        locker: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> StdResult<TokensResponse> {
+        query_locks_by_locker(deps, locker, start_after, limit)
+    }
+
+    fn query_transfer_offer(
+        &self,
+        deps: Deps,
+        token_id: String,
+    ) -> StdResult<TransferOfferResponse> {
+        query_transfer_offer(deps, token_id)
+    }
+
+    fn query_transfer_offers_by_recipient(
+        &self,
+        deps: Deps,
+        recipient: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> StdResult<TransferOffersResponse> {
+        query_transfer_offers_by_recipient(deps, recipient, start_after, limit)
+    }
+
+    fn query_permit_nonce(&self, deps: Deps, owner: String) -> StdResult<PermitNonceResponse> {
+        query_permit_nonce(deps, owner)
+    }
+
+    fn query_voucher_signer(&self, deps: Deps) -> StdResult<VoucherSignerResponse> {
+        query_voucher_signer(deps)
+    }
+
+    fn query_is_voucher_redeemed(
+        &self,
+        deps: Deps,
+        voucher_hash: HexBinary,
+    ) -> StdResult<IsVoucherRedeemedResponse> {
+        query_is_voucher_redeemed(deps, voucher_hash)
+    }
+
+    fn query_hooks(
+        &self,
+        deps: Deps,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> StdResult<HooksResponse> {
+        query_hooks(deps, start_after, limit)
+    }
+
+    fn query_merkle_root(&self, deps: Deps) -> StdResult<MerkleRootResponse> {
+        query_merkle_root(deps)
+    }
+
+    fn query_is_claimed(&self, deps: Deps, leaf: HexBinary) -> StdResult<IsClaimedResponse> {
+        query_is_claimed(deps, leaf)
+    }
+
+    fn query_token_history(
+        &self,
+        deps: Deps,
+        token_id: String,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    ) -> StdResult<TokenHistoryResponse> {
+        query_token_history(deps, token_id, start_after, limit)
+    }
+
+    fn query_burned_tokens(
+        &self,
+        deps: Deps,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> StdResult<BurnedTokensResponse> {
+        query_burned_tokens(deps, start_after, limit)
+    }
+
+    fn query_is_burned(&self, deps: Deps, token_id: String) -> StdResult<IsBurnedResponse> {
+        query_is_burned(deps, token_id)
+    }
+
     fn query_all_nft_info(
         &self,
         deps: Deps,
@@ -812,6 +1786,19 @@ pub trait Cw721Query<
     fn query_withdraw_address(&self, deps: Deps) -> StdResult<Option<String>> {
         query_withdraw_address(deps)
     }
+
+    fn query_royalty_info(
+        &self,
+        deps: Deps,
+        token_id: String,
+        sale_price: Uint128,
+    ) -> StdResult<RoyaltiesInfoResponse> {
+        query_royalty_info(deps, token_id, sale_price)
+    }
+
+    fn query_check_royalties(&self, deps: Deps) -> StdResult<CheckRoyaltiesResponse> {
+        query_check_royalties(deps)
+    }
 }
 
 /// Generic trait with onchain nft and collection extensions used to call query and execute messages for a given CW721 addr.
@@ -929,6 +1916,33 @@ pub trait Cw721Calls<
         Ok(res.count)
     }
 
+    fn balance_of<T: Into<String>>(&self, querier: &QuerierWrapper, owner: T) -> StdResult<u64> {
+        let req = Cw721QueryMsg::BalanceOf {
+            owner: owner.into(),
+        };
+        let res: BalanceOfResponse = self.query(querier, req)?;
+        Ok(res.balance)
+    }
+
+    fn royalty_info<T: Into<String>>(
+        &self,
+        querier: &QuerierWrapper,
+        token_id: T,
+        sale_price: Uint128,
+    ) -> StdResult<RoyaltiesInfoResponse> {
+        let req = Cw721QueryMsg::RoyaltyInfo {
+            token_id: token_id.into(),
+            sale_price,
+        };
+        self.query(querier, req)
+    }
+
+    fn check_royalties(&self, querier: &QuerierWrapper) -> StdResult<bool> {
+        let req = Cw721QueryMsg::CheckRoyalties {};
+        let res: CheckRoyaltiesResponse = self.query(querier, req)?;
+        Ok(res.royalty_payments)
+    }
+
     /// This is a helper to get the metadata and extension data in one call
     fn config<U: DeserializeOwned>(
         &self,